@@ -0,0 +1,28 @@
+//! Compares the specialized `f32` NED/ENU conversions with the generic conversion path.
+//!
+//! Run with `cargo bench -p coordinate-frame --bench conversions`.
+
+use coordinate_frame::{enu_to_ned, ned_to_enu, EastNorthUp, NorthEastDown};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn ned_enu(c: &mut Criterion) {
+    let ned = NorthEastDown::new(1.0_f32, 2.0, 3.0);
+    let enu = EastNorthUp::new(1.0_f32, 2.0, 3.0);
+
+    let mut group = c.benchmark_group("ned_to_enu");
+    group.bench_function("specialized", |b| b.iter(|| ned_to_enu(black_box(ned))));
+    group.bench_function("to_enu", |b| b.iter(|| black_box(ned).to_enu()));
+    group.bench_function("generic", |b| b.iter(|| EastNorthUp::from(black_box(ned))));
+    group.finish();
+
+    let mut group = c.benchmark_group("enu_to_ned");
+    group.bench_function("specialized", |b| b.iter(|| enu_to_ned(black_box(enu))));
+    group.bench_function("to_ned", |b| b.iter(|| black_box(enu).to_ned()));
+    group.bench_function("generic", |b| {
+        b.iter(|| NorthEastDown::from(black_box(enu)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, ned_enu);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Declares user-defined newtypes as aliases of library frames.
+
+/// Declares a newtype around a library frame as an alias of that frame.
+///
+/// The newtype must be a tuple struct wrapping the frame, either generic over the
+/// component type, e.g. `struct BodyFrame<T>(NorthWestUp<T>)`, or for a fixed
+/// component type, e.g. `struct BodyFrame(NorthWestUp<f32>)`. The macro generates
+/// [`From`] conversions in both directions as well as `to_ned` and `to_enu`
+/// functions delegating to the wrapped frame.
+///
+/// ## Example
+/// ```
+/// use coordinate_frame::{impl_frame_alias, NorthEastDown, NorthWestUp};
+///
+/// struct BodyFrame<T>(NorthWestUp<T>);
+/// impl_frame_alias!(BodyFrame => NorthWestUp);
+///
+/// let body = BodyFrame::from(NorthWestUp::new(1.0, 2.0, 3.0));
+/// assert_eq!(body.to_ned(), NorthEastDown::new(1.0, -2.0, -3.0));
+/// ```
+#[macro_export]
+macro_rules! impl_frame_alias {
+    ($alias:ident => $frame:ident < $t:ty >) => {
+        impl ::core::convert::From<$frame<$t>> for $alias {
+            fn from(value: $frame<$t>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::core::convert::From<$alias> for $frame<$t> {
+            fn from(value: $alias) -> Self {
+                value.0
+            }
+        }
+
+        impl $alias {
+            /// Converts this type to a [`NorthEastDown`]($crate::NorthEastDown) instance.
+            pub fn to_ned(&self) -> $crate::NorthEastDown<$t> {
+                $crate::CoordinateFrame::to_ned(&self.0)
+            }
+
+            /// Converts this type to an [`EastNorthUp`]($crate::EastNorthUp) instance.
+            pub fn to_enu(&self) -> $crate::EastNorthUp<$t> {
+                $crate::CoordinateFrame::to_enu(&self.0)
+            }
+        }
+    };
+    ($alias:ident => $frame:ident) => {
+        impl<T> ::core::convert::From<$frame<T>> for $alias<T> {
+            fn from(value: $frame<T>) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T> ::core::convert::From<$alias<T>> for $frame<T> {
+            fn from(value: $alias<T>) -> Self {
+                value.0
+            }
+        }
+
+        impl<T> $alias<T> {
+            /// Converts this type to a [`NorthEastDown`]($crate::NorthEastDown) instance.
+            pub fn to_ned(&self) -> $crate::NorthEastDown<T>
+            where
+                T: Copy + $crate::SaturatingNeg<Output = T>,
+            {
+                $crate::CoordinateFrame::to_ned(&self.0)
+            }
+
+            /// Converts this type to an [`EastNorthUp`]($crate::EastNorthUp) instance.
+            pub fn to_enu(&self) -> $crate::EastNorthUp<T>
+            where
+                T: Copy + $crate::SaturatingNeg<Output = T>,
+            {
+                $crate::CoordinateFrame::to_enu(&self.0)
+            }
+        }
+    };
+}
@@ -0,0 +1,18 @@
+/// An axis of a coordinate frame, independent of its physical direction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(u8)]
+pub enum Axis {
+    /// The first dimension.
+    X = 0,
+    /// The second dimension.
+    Y = 1,
+    /// The third dimension.
+    Z = 2,
+}
+
+impl Axis {
+    /// Returns the index of the axis in the component array.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{CoordinateFrame, Direction, NorthEastDown, SaturatingNeg};
+use core::marker::PhantomData;
+
+/// An error returned by [`FrameBuilder::build`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FrameBuilderError {
+    /// Neither direction of an axis was provided, e.g. neither north nor south.
+    Missing(Direction, Direction),
+    /// Both directions of an axis were provided, e.g. both up and down.
+    Conflicting(Direction, Direction),
+}
+
+/// Incrementally constructs a coordinate frame `F` from its physical directions.
+///
+/// Exactly one direction per axis must be provided, i.e. one of north or south, one of
+/// east or west and one of up or down. Providing the same direction again overwrites
+/// the previous value.
+///
+/// ## Example
+/// ```
+/// use coordinate_frame::{EastNorthUp, FrameBuilder};
+///
+/// let enu: EastNorthUp<i32> = FrameBuilder::new()
+///     .set_north(1)
+///     .set_west(2)
+///     .set_down(3)
+///     .build()
+///     .unwrap();
+/// assert_eq!(enu, EastNorthUp::new(-2, 1, -3));
+/// ```
+pub struct FrameBuilder<F>
+where
+    F: CoordinateFrame,
+{
+    /// The provided values, indexed by [`Direction`].
+    values: [Option<F::Type>; 6],
+    _frame: PhantomData<F>,
+}
+
+impl<F> FrameBuilder<F>
+where
+    F: CoordinateFrame,
+{
+    /// Creates a new builder without any directions set.
+    pub fn new() -> Self {
+        Self {
+            values: [None, None, None, None, None, None],
+            _frame: PhantomData,
+        }
+    }
+
+    /// Sets the value along the specified direction.
+    pub fn set(mut self, direction: Direction, value: F::Type) -> Self {
+        self.values[direction as usize] = Some(value);
+        self
+    }
+
+    /// Sets the _north_ component.
+    pub fn set_north(self, value: F::Type) -> Self {
+        self.set(Direction::North, value)
+    }
+
+    /// Sets the _east_ component.
+    pub fn set_east(self, value: F::Type) -> Self {
+        self.set(Direction::East, value)
+    }
+
+    /// Sets the _south_ component.
+    pub fn set_south(self, value: F::Type) -> Self {
+        self.set(Direction::South, value)
+    }
+
+    /// Sets the _west_ component.
+    pub fn set_west(self, value: F::Type) -> Self {
+        self.set(Direction::West, value)
+    }
+
+    /// Sets the _up_ component.
+    pub fn set_up(self, value: F::Type) -> Self {
+        self.set(Direction::Up, value)
+    }
+
+    /// Sets the _down_ component.
+    pub fn set_down(self, value: F::Type) -> Self {
+        self.set(Direction::Down, value)
+    }
+
+    /// Validates the provided directions and constructs the coordinate frame.
+    ///
+    /// ## Errors
+    /// Returns [`FrameBuilderError::Missing`] if no direction of an axis was provided and
+    /// [`FrameBuilderError::Conflicting`] if both directions of an axis were provided.
+    pub fn build(self) -> Result<F, FrameBuilderError>
+    where
+        F: From<NorthEastDown<F::Type>>,
+        F::Type: SaturatingNeg<Output = F::Type>,
+    {
+        let [north, east, south, west, up, down] = self.values;
+        let north = Self::resolve(Direction::North, north, Direction::South, south)?;
+        let east = Self::resolve(Direction::East, east, Direction::West, west)?;
+        let down = Self::resolve(Direction::Down, down, Direction::Up, up)?;
+        Ok(F::from(NorthEastDown::new(north, east, down)))
+    }
+
+    /// Resolves the value along the `positive` direction from a pair of opposing directions.
+    fn resolve(
+        positive: Direction,
+        value: Option<F::Type>,
+        negative: Direction,
+        opposite: Option<F::Type>,
+    ) -> Result<F::Type, FrameBuilderError>
+    where
+        F::Type: SaturatingNeg<Output = F::Type>,
+    {
+        match (value, opposite) {
+            (Some(value), None) => Ok(value),
+            (None, Some(opposite)) => Ok(opposite.saturating_neg()),
+            (Some(_), Some(_)) => Err(FrameBuilderError::Conflicting(positive, negative)),
+            (None, None) => Err(FrameBuilderError::Missing(positive, negative)),
+        }
+    }
+}
+
+impl<F> Default for FrameBuilder<F>
+where
+    F: CoordinateFrame,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,28 @@
+//! Specialized conversions for frequently used frames and element types.
+
+use crate::{EastNorthUp, NorthEastDown};
+
+/// Converts a [`NorthEastDown`] coordinate to [`EastNorthUp`] by directly permuting and
+/// negating the components.
+///
+/// This is specialized for `f32` in order to bypass the generic
+/// [`SaturatingNeg`](crate::SaturatingNeg) machinery on hot paths; [`NorthEastDown::to_enu`]
+/// delegates to it for `f32`. Note that this function cannot be `const` as floating-point
+/// arithmetic in constant functions requires a more recent compiler than the crate's minimum
+/// supported Rust version.
+#[inline]
+pub fn ned_to_enu(ned: NorthEastDown<f32>) -> EastNorthUp<f32> {
+    let [north, east, down] = ned.0;
+    EastNorthUp([east, north, -down])
+}
+
+/// Converts an [`EastNorthUp`] coordinate to [`NorthEastDown`] by directly permuting and
+/// negating the components.
+///
+/// This is specialized for `f32`; [`EastNorthUp::to_ned`] delegates to it for `f32`.
+/// See [`ned_to_enu`] for details.
+#[inline]
+pub fn enu_to_ned(enu: EastNorthUp<f32>) -> NorthEastDown<f32> {
+    let [east, north, up] = enu.0;
+    NorthEastDown([north, east, -up])
+}
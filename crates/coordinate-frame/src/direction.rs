@@ -0,0 +1,16 @@
+/// A physical direction along one of the axes of a coordinate frame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Direction {
+    /// The longitudinal axis, pointing forward.
+    North,
+    /// The lateral axis, pointing right.
+    East,
+    /// The longitudinal axis, pointing backward.
+    South,
+    /// The lateral axis, pointing left.
+    West,
+    /// The vertical axis, pointing up.
+    Up,
+    /// The vertical axis, pointing down.
+    Down,
+}
@@ -0,0 +1,135 @@
+use crate::{
+    construct_frame, convert_to_ned, CoordinateFrame, CoordinateFrameType, Direction,
+    NorthEastDown, SaturatingNeg,
+};
+
+/// A coordinate whose coordinate frame is only known at runtime.
+///
+/// Every statically typed frame such as [`NorthEastDown`](crate::NorthEastDown) converts
+/// into a `DynamicFrame` via [`From`], and back via [`TryFrom`](core::convert::TryFrom)
+/// if the runtime tag matches.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DynamicFrame<T> {
+    /// The coordinate frame type of the components.
+    frame: CoordinateFrameType,
+    /// The components, in the order of the axes of the coordinate frame.
+    components: [T; 3],
+}
+
+impl<T> DynamicFrame<T> {
+    /// Constructs a new coordinate from the coordinate frame type and its components.
+    pub const fn new(frame: CoordinateFrameType, components: [T; 3]) -> Self {
+        Self { frame, components }
+    }
+
+    /// Returns the coordinate frame type of this coordinate.
+    pub const fn frame(&self) -> CoordinateFrameType {
+        self.frame
+    }
+
+    /// Returns a reference to the components of this coordinate.
+    pub const fn components(&self) -> &[T; 3] {
+        &self.components
+    }
+
+    /// Consumes self and returns the components of this coordinate.
+    pub fn into_components(self) -> [T; 3] {
+        self.components
+    }
+
+    /// Converts this coordinate into the statically typed frame `F`, regardless of
+    /// the runtime coordinate frame type.
+    ///
+    /// Unlike the [`TryFrom`](core::convert::TryFrom) implementations, which require the
+    /// frame types to match, this converts as needed.
+    ///
+    /// ## Returns
+    /// The coordinate expressed in `F`, or `None` if the runtime coordinate frame type is
+    /// [`Other`](CoordinateFrameType::Other) or [`Undefined`](CoordinateFrameType::Undefined)
+    /// and therefore has no known axis directions.
+    pub fn convert_to<F>(&self) -> Option<F>
+    where
+        F: CoordinateFrame<Type = T> + From<NorthEastDown<T>>,
+        T: Copy + SaturatingNeg<Output = T>,
+    {
+        let [x, y, z] = self.components;
+        construct_frame(self.frame, x, y, z)
+    }
+
+    /// Returns the component pointing in the specified direction.
+    ///
+    /// Returns `None` if the direction is not representable, i.e. if the coordinate
+    /// frame type is [`Other`](CoordinateFrameType::Other) or
+    /// [`Undefined`](CoordinateFrameType::Undefined).
+    pub fn get(&self, direction: Direction) -> Option<T>
+    where
+        T: Copy + SaturatingNeg<Output = T>,
+    {
+        let ned = convert_to_ned(self.frame, self.components).ok()?;
+        Some(ned.component(direction))
+    }
+}
+
+impl<T> Default for DynamicFrame<T>
+where
+    T: Default,
+{
+    /// Returns a coordinate in the default coordinate frame type, i.e.
+    /// [`NorthEastDown`](CoordinateFrameType::NorthEastDown), with defaulted (i.e. zero)
+    /// components.
+    fn default() -> Self {
+        Self::new(CoordinateFrameType::default(), Default::default())
+    }
+}
+
+impl<T> core::fmt::Debug for DynamicFrame<T>
+where
+    T: core::fmt::Debug,
+{
+    /// Formats the coordinate like the statically typed frame of its runtime tag,
+    /// e.g. `NorthEastDown([1, 2, 3])`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+        core::fmt::Display::fmt(&self.frame, f)?;
+        f.write_char('(')?;
+        core::fmt::Debug::fmt(&self.components, f)?;
+        f.write_char(')')
+    }
+}
+
+impl<T> core::fmt::Display for DynamicFrame<T>
+where
+    T: core::fmt::Display,
+{
+    /// Formats the coordinate like the statically typed frame of its runtime tag,
+    /// e.g. `NorthEastDown(1, 2, 3)`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+        core::fmt::Display::fmt(&self.frame, f)?;
+        f.write_char('(')?;
+        core::fmt::Display::fmt(&self.components[0], f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.components[1], f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.components[2], f)?;
+        f.write_char(')')
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl<T> defmt::Format for DynamicFrame<T>
+where
+    T: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "{}({}, {}, {})",
+            self.frame,
+            self.components[0],
+            self.components[1],
+            self.components[2]
+        )
+    }
+}
@@ -0,0 +1,8 @@
+/// The handedness of a coordinate frame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Handedness {
+    /// The cross product of the `x` and `y` axes yields the `z` axis.
+    Right,
+    /// The cross product of the `x` and `y` axes yields the negated `z` axis.
+    Left,
+}
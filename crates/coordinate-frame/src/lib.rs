@@ -34,18 +34,59 @@
 //! let axis = NorthEastDown::<f64>::z_axis();
 //! assert_eq!(axis, [0.0, 0.0, 1.0]);
 //! ```
+//!
+//! ## Const conversions
+//! Every frame provides `const fn to_<frame>` conversions to every other frame, e.g.
+//! `to_east_down_north`. Conversions that only permute the components are available for any
+//! `Copy` type. Conversions that negate components are only provided for the signed integer types
+//! `i8`, `i16`, `i32`, `i64`, `i128` and `isize`, whose negation saturates; floating-point types
+//! are not supported, as their negation is not `const` on the minimum supported Rust version.
+//!
+//! ```
+//! use coordinate_frame::{EastDownNorth, EastNorthUp, NorthEastDown};
+//!
+//! const NED: NorthEastDown<f32> = NorthEastDown::new(1.0, 2.0, 3.0);
+//! const EDN: EastDownNorth<f32> = NED.to_east_down_north();
+//! assert_eq!(EDN, EastDownNorth::new(2.0, 3.0, 1.0));
+//!
+//! const NED_I32: NorthEastDown<i32> = NorthEastDown::new(1, 2, 3);
+//! const ENU: EastNorthUp<i32> = NED_I32.to_east_north_up();
+//! assert_eq!(ENU, EastNorthUp::new(2, 1, -3));
+//! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "bytemuck"), forbid(unsafe_code))]
+#![cfg_attr(feature = "bytemuck", deny(unsafe_code))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod alias;
+mod axis;
+mod builder;
+mod conversions;
+mod direction;
+mod dynamic;
+mod handedness;
+mod rotation;
+mod sum;
 mod traits;
 
+pub use axis::*;
+pub use builder::*;
+pub use conversions::*;
 use coordinate_frame_derive::CoordinateFrame;
+pub use direction::*;
+pub use dynamic::*;
+pub use handedness::*;
+pub use rotation::*;
+pub use sum::*;
 pub use traits::*;
 
 /// A coordinate frame type.
 #[derive(CoordinateFrame, Default, Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[coordinate_frame(require_complete)]
 #[repr(u8)]
 pub enum CoordinateFrameType {
     /// Common aerospace reference frame.
@@ -161,16 +202,95 @@ pub enum CoordinateFrameType {
     Undefined = 255,
 }
 
+/// An error returned when parsing, constructing or converting coordinate frames.
 #[derive(Debug)]
 pub enum ParseCoordinateFrameError {
     /// An unknown enum variant was provided.
     UnknownVariant,
+    /// The vector has a length of zero and has no direction.
+    DegenerateVector,
+    /// The coordinate frame type does not match the requested frame.
+    FrameMismatch,
+    /// The coordinate frame type does not describe a physical frame.
+    UnsupportedFrame,
+    /// The conversion would invert the vertical direction.
+    VerticalMismatch,
+    /// The input has the wrong number of components.
+    WrongLength {
+        /// The expected number of components.
+        expected: usize,
+        /// The actual number of components.
+        got: usize,
+    },
+}
+
+impl core::fmt::Display for ParseCoordinateFrameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownVariant => f.write_str("unknown coordinate frame type"),
+            Self::DegenerateVector => f.write_str("vector has a length of zero"),
+            Self::FrameMismatch => f.write_str("coordinate frame type does not match"),
+            Self::UnsupportedFrame => {
+                f.write_str("coordinate frame type does not describe a physical frame")
+            }
+            Self::VerticalMismatch => f.write_str("conversion would invert the vertical direction"),
+            Self::WrongLength { expected, got } => {
+                write!(f, "expected {expected} components, got {got}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseCoordinateFrameError {}
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for ParseCoordinateFrameError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Self::UnknownVariant => defmt::write!(f, "unknown coordinate frame type"),
+            Self::DegenerateVector => defmt::write!(f, "vector has a length of zero"),
+            Self::FrameMismatch => defmt::write!(f, "coordinate frame type does not match"),
+            Self::UnsupportedFrame => {
+                defmt::write!(
+                    f,
+                    "coordinate frame type does not describe a physical frame"
+                )
+            }
+            Self::VerticalMismatch => {
+                defmt::write!(f, "conversion would invert the vertical direction")
+            }
+            Self::WrongLength { expected, got } => {
+                defmt::write!(f, "expected {} components, got {}", expected, got)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::*;
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_error() {
+        fn parse(value: u8) -> Result<CoordinateFrameType, Box<dyn std::error::Error>> {
+            Ok(CoordinateFrameType::try_from(value)?)
+        }
+
+        assert!(parse(0).is_ok());
+        let error = parse(200).unwrap_err();
+        assert_eq!(error.to_string(), "unknown coordinate frame type");
+
+        let error = ParseCoordinateFrameError::WrongLength {
+            expected: 3,
+            got: 4,
+        };
+        assert_eq!(error.to_string(), "expected 3 components, got 4");
+    }
+
     #[test]
     fn neu_to_ned() {
         let neu = NorthEastUp::new(0.0, 2.0, 3.0);
@@ -206,6 +326,16 @@ mod tests {
         assert_eq!(ned2.down(), -6.0);
     }
 
+    #[test]
+    fn with_derived() {
+        let ned = NorthEastDown::new(0.0, 0.0, 0.0).with_up(3.0);
+        assert_eq!(ned.down(), -3.0);
+        assert_eq!(ned.up(), 3.0);
+
+        let enu = EastNorthUp::new(1, 2, 3).with_west(4).with_south(5);
+        assert_eq!(enu, EastNorthUp::new(-4, -5, 3));
+    }
+
     #[test]
     fn map() {
         let ned = NorthEastDown::new(1.0, 2.0, -3.0);
@@ -213,6 +343,365 @@ mod tests {
         assert_eq!(ned2.north(), 2.0);
         assert_eq!(ned2.east(), 4.0);
         assert_eq!(ned2.down(), -6.0);
+
+        let narrowed: NorthEastDown<f32> = NorthEastDown::new(1.5_f64, 2.0, -3.0).map(|v| v as f32);
+        assert_eq!(narrowed, NorthEastDown::new(1.5_f32, 2.0, -3.0));
+
+        let lengths = NorthEastDown::new("a", "bb", "ccc").map_ref(|s| s.len());
+        assert_eq!(lengths, NorthEastDown::new(1, 2, 3));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn cosine_similarity() {
+        let a = NorthEastDown::new(1.0_f64, 2.0, 3.0);
+        let parallel = NorthEastDown::new(2.0, 4.0, 6.0);
+        let antiparallel = NorthEastDown::new(-2.0, -4.0, -6.0);
+        assert!((a.cosine_similarity(&parallel) - 1.0).abs() < 1e-12);
+        assert!((a.cosine_similarity(&antiparallel) + 1.0).abs() < 1e-12);
+
+        let orthogonal = NorthEastDown::new(0.0, 3.0, -2.0);
+        assert_eq!(a.cosine_similarity(&orthogonal), 0.0);
+
+        let zero = NorthEastDown::new(0.0, 0.0, 0.0);
+        assert_eq!(a.cosine_similarity(&zero), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn geometric_mean() {
+        let scales = NorthEastDown::new(1.0_f64, 2.0, 4.0);
+        assert!((scales.geometric_mean() - 2.0).abs() < 1e-12);
+
+        assert!(NorthEastDown::new(1.0_f64, 0.0, 4.0)
+            .geometric_mean()
+            .is_nan());
+        assert!(NorthEastDown::new(-1.0_f32, -2.0, 4.0)
+            .geometric_mean()
+            .is_nan());
+    }
+
+    #[test]
+    fn sub_scalar() {
+        let ned = NorthEastDown::new(3_u8, 10, 200);
+        assert_eq!(ned.saturating_sub_scalar(5), NorthEastDown::new(0, 5, 195));
+        assert_eq!(ned.checked_sub_scalar(5), None);
+        assert_eq!(
+            ned.checked_sub_scalar(3),
+            Some(NorthEastDown::new(0, 7, 197))
+        );
+    }
+
+    #[test]
+    fn checked_saturating_dot() {
+        let a = NorthEastDown::new(1_i32, 2, 3);
+        let b = NorthEastDown::new(4_i32, -5, 6);
+        assert_eq!(a.checked_dot(&b), Some(12));
+        assert_eq!(a.saturating_dot(&b), 12);
+
+        let large = NorthEastDown::new(i32::MAX, 1, 0);
+        assert_eq!(large.checked_dot(&large), None);
+        assert_eq!(large.saturating_dot(&large), i32::MAX);
+
+        let negative = NorthEastDown::new(i32::MIN, 0, 0);
+        assert_eq!(large.checked_dot(&negative), None);
+        assert_eq!(large.saturating_dot(&negative), i32::MIN);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn distance() {
+        let a = NorthEastDown::new(1.0, 2.0, 3.0);
+        let b = NorthEastDown::new(4.0, 6.0, 3.0);
+        assert_eq!(a.distance_squared(&b), 25.0);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(b.distance(&a), 5.0);
+
+        let a = NorthEastDown::new(0_u8, 4, 0);
+        let b = NorthEastDown::new(3_u8, 0, 0);
+        assert_eq!(a.distance_squared(&b), 25);
+    }
+
+    #[test]
+    fn distance_l1_linf() {
+        let a = NorthEastDown::new(1_i32, 2, 3);
+        let b = NorthEastDown::new(4_i32, -2, 3);
+        assert_eq!(a.distance_l1(&b), 7);
+        assert_eq!(b.distance_l1(&a), 7);
+        assert_eq!(a.distance_linf(&b), 4);
+
+        let a = NorthEastDown::new(1_u8, 5, 3);
+        let b = NorthEastDown::new(3_u8, 0, 3);
+        assert_eq!(a.distance_l1(&b), 7);
+        assert_eq!(a.distance_linf(&b), 5);
+    }
+
+    #[test]
+    fn checked_distance_sq() {
+        let a = NorthEastDown::new(1_i32, 2, 3);
+        let b = NorthEastDown::new(4_i32, -2, 3);
+        assert_eq!(a.checked_distance_sq(&b), Some(25));
+        assert_eq!(b.checked_distance_sq(&a), Some(25));
+
+        let far = NorthEastDown::new(50_000_i32, 0, 0);
+        assert_eq!(a.checked_distance_sq(&far), None);
+
+        let min = NorthEastDown::new(i32::MIN, 0, 0);
+        let max = NorthEastDown::new(i32::MAX, 0, 0);
+        assert_eq!(min.checked_distance_sq(&max), None);
+
+        let a = NorthEastDown::new(1_u8, 2, 3);
+        let b = NorthEastDown::new(3_u8, 0, 3);
+        assert_eq!(a.checked_distance_sq(&b), Some(8));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn horizontal_norm() {
+        assert_eq!(NorthEastDown::new(3.0, 4.0, 100.0).horizontal_norm(), 5.0);
+        assert_eq!(UpWestSouth::new(100.0, 3.0, -4.0).horizontal_norm(), 5.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn heading_diff() {
+        let from_degrees = |degrees: f64| {
+            let radians = degrees.to_radians();
+            NorthEastDown::new(radians.cos(), radians.sin(), 1.0)
+        };
+
+        let a = from_degrees(170.0);
+        let b = from_degrees(-170.0);
+        assert!((a.heading() - 170_f64.to_radians()).abs() < 1e-12);
+        assert!((a.heading_diff(&b) - 20_f64.to_radians()).abs() < 1e-12);
+        assert!((b.heading_diff(&a) + 20_f64.to_radians()).abs() < 1e-12);
+
+        let c = from_degrees(10.0);
+        assert!((c.heading_diff(&a) - 160_f64.to_radians()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn to_f64() {
+        let ned = NorthEastDown::new(1_i16, i16::MIN, i16::MAX);
+        let widened: NorthEastDown<f64> = ned.to_f64();
+        assert_eq!(widened, NorthEastDown::new(1.0, -32768.0, 32767.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn norm() {
+        assert_eq!(NorthEastDown::new(3.0_f32, 4.0, 0.0).norm(), 5.0);
+        assert_eq!(NorthEastDown::new(3.0_f64, 4.0, 0.0).norm(), 5.0);
+        assert_eq!(EastNorthUp::new(2.0_f64, -3.0, 6.0).norm(), 7.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn normalize() {
+        let ned = NorthEastDown::new(1.0_f64, -2.0, 3.0);
+        assert!((ned.normalize().norm() - 1.0).abs() < 1e-12);
+        assert!((ned.normalize_or_zero().norm() - 1.0).abs() < 1e-12);
+
+        let zero = NorthEastDown::new(0.0_f64, -0.0, 0.0);
+        assert_eq!(zero.normalize().to_bits(), zero.to_bits());
+        assert_eq!(zero.normalize_or_zero(), NorthEastDown::new(0.0, 0.0, 0.0));
+
+        let nan = NorthEastDown::new(f64::NAN, 0.0, 0.0);
+        assert_eq!(nan.normalize_or_zero(), NorthEastDown::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn norm_l1() {
+        assert_eq!(NorthEastDown::new(1.0, -2.0, 3.0).norm_l1(), 6.0);
+        assert_eq!(NorthEastDown::new(-1_i32, 2, -3).norm_l1(), 6);
+        assert_eq!(NorthEastDown::new(1_u8, 2, 3).norm_l1(), 6);
+    }
+
+    #[test]
+    fn norm_linf() {
+        assert_eq!(NorthEastDown::new(1, -5, 3).norm_linf(), 5);
+        assert_eq!(NorthEastDown::new(1.0, 2.0, -3.0).norm_linf(), 3.0);
+    }
+
+    #[test]
+    fn is_unit() {
+        let unit = NorthEastDown::new(0.6, 0.0, 0.8);
+        assert!(unit.is_unit(1e-6));
+
+        let long = NorthEastDown::new(0.0, 2.0, 0.0);
+        assert!(!long.is_unit(1e-6));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn with_norm() {
+        let ned = NorthEastDown::new(3.0, 0.0, 4.0);
+        assert_eq!(ned.with_norm(10.0), NorthEastDown::new(6.0, 0.0, 8.0));
+
+        let zero = NorthEastDown::new(0.0, 0.0, 0.0);
+        assert_eq!(zero.with_norm(10.0), zero);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = NorthEastDown::new(1.0, 2.0, 3.0);
+        let b = NorthEastDown::new(3.0, -2.0, 4.0);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), NorthEastDown::new(2.0, 0.0, 3.5));
+        assert_eq!(a.lerp(&b, 2.0), NorthEastDown::new(5.0, -6.0, 5.0));
+
+        assert_eq!(a.lerp_clamped(&b, 2.0), b);
+        assert_eq!(a.lerp_clamped(&b, -1.0), a);
+        assert_eq!(a.lerp_clamped(&b, 0.5), a.lerp(&b, 0.5));
+    }
+
+    #[test]
+    fn bits() {
+        let nan = f32::from_bits(0x7fc0_1234);
+        let ned = NorthEastDown::new(nan, -0.0, 1.5);
+        let bits = ned.to_bits();
+        assert_eq!(bits, [0x7fc0_1234, 0x8000_0000, 1.5_f32.to_bits()]);
+        assert_eq!(NorthEastDown::<f32>::from_bits(bits).to_bits(), bits);
+
+        let enu = EastNorthUp::new(f64::from_bits(0x7ff8_0000_dead_beef), 0.0, -0.0);
+        let bits = enu.to_bits();
+        assert_eq!(EastNorthUp::<f64>::from_bits(bits).to_bits(), bits);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_unit() {
+        let ned = NorthEastDown::new(3.0, 0.0, 4.0);
+        let unit = ned.try_unit().expect("vector is not degenerate");
+        assert_eq!(unit, NorthEastDown::new(0.6, 0.0, 0.8));
+
+        let zero = NorthEastDown::new(0.0, 0.0, 0.0);
+        assert!(matches!(
+            zero.try_unit(),
+            Err(ParseCoordinateFrameError::DegenerateVector)
+        ));
+    }
+
+    #[test]
+    fn saturating_norm() {
+        assert_eq!(NorthEastDown::new(3_i32, 4, 0).saturating_norm(), 5);
+        assert_eq!(NorthEastDown::new(2_i32, -3, 6).saturating_norm(), 7);
+        assert_eq!(NorthEastDown::new(1_u8, 1, 1).saturating_norm(), 1);
+
+        let huge = NorthEastDown::new(i32::MAX, i32::MIN, i32::MAX);
+        assert_eq!(huge.saturating_norm_sq(), i32::MAX);
+        assert_eq!(huge.saturating_norm(), 46340);
+
+        for value in 0..=1000_u32 {
+            let expected = f64::from(value).sqrt().floor() as u32;
+            assert_eq!(IntegerSqrt::isqrt(value), expected);
+        }
+    }
+
+    #[test]
+    fn map_axis() {
+        let ned = NorthEastDown::new(1.0, 2.0, -3.0);
+        let ned2 = ned.map_axis(Axis::Z, |z| z * 2.0);
+        assert_eq!(ned2.north(), 1.0);
+        assert_eq!(ned2.east(), 2.0);
+        assert_eq!(ned2.down(), -6.0);
+    }
+
+    #[test]
+    fn frame_builder() {
+        let ned: NorthEastDown<i32> = FrameBuilder::new()
+            .set_south(1)
+            .set_east(2)
+            .set_down(3)
+            .build()
+            .expect("all axes are specified");
+        assert_eq!(ned, NorthEastDown::new(-1, 2, 3));
+
+        let conflicting = FrameBuilder::<EastNorthUp<i32>>::new()
+            .set_north(1)
+            .set_east(2)
+            .set_up(3)
+            .set_down(4)
+            .build();
+        assert_eq!(
+            conflicting,
+            Err(FrameBuilderError::Conflicting(
+                Direction::Down,
+                Direction::Up
+            ))
+        );
+
+        let missing = FrameBuilder::<EastNorthUp<i32>>::new()
+            .set_north(1)
+            .set_up(3)
+            .build();
+        assert_eq!(
+            missing,
+            Err(FrameBuilderError::Missing(Direction::East, Direction::West))
+        );
+    }
+
+    #[test]
+    fn dynamic_frame() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        let dynamic = DynamicFrame::from(ned);
+        assert_eq!(dynamic.frame(), CoordinateFrameType::NorthEastDown);
+        assert_eq!(dynamic.components(), &[1.0, 2.0, 3.0]);
+
+        let roundtrip = NorthEastDown::try_from(dynamic).expect("frame types match");
+        assert_eq!(roundtrip, ned);
+
+        assert!(matches!(
+            EastNorthUp::try_from(dynamic),
+            Err(ParseCoordinateFrameError::FrameMismatch)
+        ));
+    }
+
+    #[test]
+    fn dynamic_frame_convert_to() {
+        let dynamic = DynamicFrame::new(CoordinateFrameType::NorthEastDown, [1.0, 2.0, 3.0]);
+        let enu: EastNorthUp<f64> = dynamic.convert_to().expect("frame type is physical");
+        assert_eq!(enu, EastNorthUp::new(2.0, 1.0, -3.0));
+
+        let ned: Option<NorthEastDown<f64>> = dynamic.convert_to();
+        assert_eq!(ned, Some(NorthEastDown::new(1.0, 2.0, 3.0)));
+
+        let other = DynamicFrame::new(CoordinateFrameType::Other, [1.0, 2.0, 3.0]);
+        assert_eq!(other.convert_to::<NorthEastDown<f64>>(), None);
+    }
+
+    #[test]
+    fn dynamic_frame_default() {
+        let dynamic = DynamicFrame::<f32>::default();
+        assert_eq!(dynamic.frame(), CoordinateFrameType::NorthEastDown);
+        assert_eq!(dynamic.components(), &[0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn west_base_vectors() {
+        let mut west_frames = 0;
+        crate::for_each_base_vectors(|frame, axes: [[f64; 3]; 3]| {
+            let directions = frame.components().expect("physical frame");
+            let Some(west) = directions.iter().position(|&d| d == Direction::West) else {
+                return;
+            };
+            assert_eq!(axes[west], [-1.0, 0.0, 0.0], "{frame}");
+            west_frames += 1;
+        });
+        assert_eq!(west_frames, 24);
+    }
+
+    #[test]
+    fn axis_in_ned() {
+        let enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        assert_eq!(enu.axis_in_ned(Axis::X), NorthEastDown::new(0.0, 1.0, 0.0));
+        assert_eq!(enu.axis_in_ned(Axis::Y), NorthEastDown::new(1.0, 0.0, 0.0));
+        assert_eq!(enu.axis_in_ned(Axis::Z), NorthEastDown::new(0.0, 0.0, -1.0));
+
+        let wds = WestDownSouth::new(1, 2, 3);
+        assert_eq!(wds.axis_in_ned(Axis::X), NorthEastDown::new(0, -1, 0));
+        assert_eq!(wds.axis_in_ned(Axis::Z), NorthEastDown::new(-1, 0, 0));
     }
 
     #[test]
@@ -224,6 +713,676 @@ mod tests {
         assert_eq!(ned.down(), -3.0);
     }
 
+    #[test]
+    fn convert_to_ned_enu() {
+        let data = [1, 2, 3];
+        let ned = crate::convert_to_ned(CoordinateFrameType::EastNorthUp, data).unwrap();
+        assert_eq!(ned, NorthEastDown::new(2, 1, -3));
+        let ned = crate::convert_to_ned(CoordinateFrameType::NorthEastDown, data).unwrap();
+        assert_eq!(ned, NorthEastDown::new(1, 2, 3));
+        let ned = crate::convert_to_ned(CoordinateFrameType::UpSouthWest, data).unwrap();
+        assert_eq!(ned, NorthEastDown::new(-2, -3, -1));
+
+        let enu = crate::convert_to_enu(CoordinateFrameType::NorthEastDown, data).unwrap();
+        assert_eq!(enu, EastNorthUp::new(2, 1, -3));
+        let enu = crate::convert_to_enu(CoordinateFrameType::WestDownSouth, data).unwrap();
+        assert_eq!(enu, EastNorthUp::new(-1, -3, -2));
+
+        assert!(matches!(
+            crate::convert_to_ned(CoordinateFrameType::Other, data),
+            Err(ParseCoordinateFrameError::UnsupportedFrame)
+        ));
+        assert!(matches!(
+            crate::convert_to_enu(CoordinateFrameType::Undefined, data),
+            Err(ParseCoordinateFrameError::UnsupportedFrame)
+        ));
+    }
+
+    #[test]
+    fn from_row_column() {
+        let identity = [[1, 0, 0], [0, 1, 0], [0, 0, 1]];
+        assert_eq!(NorthEastDown::from_column(&identity, 1), &[0, 1, 0]);
+
+        let matrix = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(NorthEastDown::from_row(&matrix, 1), &[4, 5, 6]);
+        assert_eq!(NorthEastDown::from_column(&matrix, 2), &[3, 6, 9]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(value: &impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = NorthEastDown::new(1, 2, 3);
+        let b = NorthEastDown::new(1, 2, 3);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_eq!(hash_of(&a), hash_of(&[1, 2, 3]));
+
+        let mut set = HashSet::new();
+        set.insert(a);
+        assert!(set.contains(&[1, 2, 3]));
+        assert!(!set.contains(&[3, 2, 1]));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn hash_set_of_frames() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(NorthEastDown::new(1, 2, 3)));
+        assert!(set.insert(NorthEastDown::new(3, 2, 1)));
+        assert!(!set.insert(NorthEastDown::new(1, 2, 3)));
+        assert_eq!(set.len(), 2);
+
+        fn assert_hash<T: core::hash::Hash>(_: &T) {}
+        assert_hash(&UpSouthWest::new(1u8, 2, 3));
+    }
+
+    #[test]
+    fn as_ref_self() {
+        fn north_of(ned: impl AsRef<NorthEastDown<f32>>) -> f32 {
+            ned.as_ref().north()
+        }
+
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(north_of(ned), 1.0);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut ned = NorthEastDown::new(1, 2, 3);
+
+        let mut sum = 0;
+        for value in &ned {
+            sum += value;
+        }
+        assert_eq!(sum, 6);
+
+        for value in &mut ned {
+            *value *= 10;
+        }
+
+        let mut components = ned.into_iter();
+        assert_eq!(components.next(), Some(10));
+        assert_eq!(components.next(), Some(20));
+        assert_eq!(components.next(), Some(30));
+        assert_eq!(components.next(), None);
+        assert_eq!(ned.into_iter().sum::<i32>(), 60);
+    }
+
+    #[test]
+    fn into_iter_mut() {
+        let mut enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        for c in &mut enu {
+            *c *= 2.0;
+        }
+        assert_eq!(enu, EastNorthUp::new(2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_iter() {
+        let values = vec![1.0, 2.0, 3.0, 4.0];
+        let ned: NorthEastDown<f64> = values.into_iter().collect();
+        assert_eq!(ned, NorthEastDown::new(1.0, 2.0, 3.0));
+
+        let enu = EastNorthUp::from_iter((1..).map(|i| i * 10));
+        assert_eq!(enu, EastNorthUp::new(10, 20, 30));
+    }
+
+    #[test]
+    fn try_from_iter() {
+        let ned = NorthEastDown::try_from_iter([1, 2, 3, 4]);
+        assert!(matches!(ned, Ok(ned) if ned == NorthEastDown::new(1, 2, 3)));
+
+        let ned = NorthEastDown::<i32>::try_from_iter([1, 2]);
+        assert!(matches!(
+            ned,
+            Err(ParseCoordinateFrameError::WrongLength {
+                expected: 3,
+                got: 2
+            })
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_iter_too_short() {
+        let _: NorthEastDown<i32> = [1, 2].into_iter().collect();
+    }
+
+    #[test]
+    fn checked_saturating_sum() {
+        let values = [
+            NorthEastDown::new(1i8, 2, 3),
+            NorthEastDown::new(10, 20, 30),
+            NorthEastDown::new(120, -100, 0),
+        ];
+        assert_eq!(
+            crate::checked_sum(values.iter().copied().take(2)),
+            Some(NorthEastDown::new(11, 22, 33))
+        );
+        assert_eq!(crate::checked_sum(values), None::<NorthEastDown<i8>>);
+        assert_eq!(
+            crate::saturating_sum(values),
+            NorthEastDown::new(127, -78, 33)
+        );
+        assert_eq!(
+            crate::saturating_sum(core::iter::empty::<NorthEastDown<u8>>()),
+            NorthEastDown::new(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn frame_alias() {
+        struct BodyFrame<T>(NorthWestUp<T>);
+        impl_frame_alias!(BodyFrame => NorthWestUp);
+
+        struct SensorFrame(EastSouthDown<i16>);
+        impl_frame_alias!(SensorFrame => EastSouthDown<i16>);
+
+        let body = BodyFrame::from(NorthWestUp::new(1.0, 2.0, 3.0));
+        assert_eq!(body.to_ned(), NorthEastDown::new(1.0, -2.0, -3.0));
+        assert_eq!(body.to_enu(), EastNorthUp::new(-2.0, 1.0, 3.0));
+        assert_eq!(NorthWestUp::from(body), NorthWestUp::new(1.0, 2.0, 3.0));
+
+        let sensor: SensorFrame = EastSouthDown::new(1, 2, 3).into();
+        assert_eq!(sensor.to_ned(), NorthEastDown::new(-2, 1, 3));
+        assert_eq!(sensor.to_enu(), EastNorthUp::new(1, -2, -3));
+    }
+
+    #[test]
+    fn abs_min_max_clamp() {
+        let a = NorthEastDown::new(-1.0, 2.0, -3.0);
+        let b = NorthEastDown::new(1.0, -2.0, -4.0);
+        assert_eq!(a.abs(), NorthEastDown::new(1.0, 2.0, 3.0));
+        assert_eq!(NorthEastDown::new(i8::MIN, -1, 0).abs().0, [i8::MAX, 1, 0]);
+
+        assert_eq!(a.min(&b), NorthEastDown::new(-1.0, -2.0, -4.0));
+        assert_eq!(a.max(&b), NorthEastDown::new(1.0, 2.0, -3.0));
+
+        let lo = NorthEastDown::new(-10, -10, -10);
+        let hi = NorthEastDown::new(10, 10, 10);
+        let value = NorthEastDown::new(-20, 5, 20);
+        assert_eq!(value.clamp(&lo, &hi), NorthEastDown::new(-10, 5, 10));
+
+        // Component-wise, not lexicographic.
+        let a = NorthEastDown::new(1, 5, 0);
+        let b = NorthEastDown::new(2, 3, 0);
+        assert_eq!(a.min(&b), NorthEastDown::new(1, 3, 0));
+        assert_eq!(a.max(&b), NorthEastDown::new(2, 5, 0));
+    }
+
+    #[test]
+    fn closest_frame() {
+        let ned = [
+            [0.99, 0.05, -0.02], //
+            [-0.04, 0.98, 0.1],
+            [0.01, -0.1, 0.97],
+        ];
+        assert_eq!(
+            crate::closest_frame(&ned),
+            CoordinateFrameType::NorthEastDown
+        );
+
+        for &frame in CoordinateFrameType::PHYSICAL {
+            let matrix =
+                CoordinateFrameType::conversion_matrix(frame, CoordinateFrameType::NorthEastDown)
+                    .expect("physical frame");
+            assert_eq!(crate::closest_frame(&matrix), frame);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn dynamic_frame_display() {
+        let enu = EastNorthUp::new(1, 2, 3);
+        let dynamic = DynamicFrame::from(enu);
+        assert_eq!(dynamic.to_string(), enu.to_string());
+        assert_eq!(dynamic.to_string(), "EastNorthUp(1, 2, 3)");
+        assert_eq!(format!("{dynamic:?}"), format!("{enu:?}"));
+    }
+
+    #[test]
+    fn with_each() {
+        let ned = NorthEastDown::new(0, 0, 0);
+        let ned = ned.with_each([|x| x + 1, |y| y * 2, |z| z - 1]);
+        assert_eq!(ned, NorthEastDown::new(1, 0, -1));
+    }
+
+    #[test]
+    fn flip_frame_saturation() {
+        let ned = NorthEastDown::new(i8::MIN, 0, i8::MAX);
+        let expected = SouthWestUp::new(i8::MAX, 0, -i8::MAX);
+        assert_eq!(ned.flip_frame(), expected);
+        assert_eq!(ned.saturating_flip_frame(), expected);
+        assert_eq!(ned.checked_flip_frame(), None);
+
+        let ned = NorthEastDown::new(i8::MIN + 1, 0, i8::MAX);
+        assert_eq!(
+            ned.checked_flip_frame(),
+            Some(SouthWestUp::new(i8::MAX, 0, -i8::MAX))
+        );
+    }
+
+    #[test]
+    fn clone_count() {
+        use core::cell::Cell;
+
+        /// A value that counts how often it was cloned.
+        #[derive(Debug, PartialEq)]
+        struct Counted<'a>(i32, &'a Cell<usize>);
+
+        impl Clone for Counted<'_> {
+            fn clone(&self) -> Self {
+                self.1.set(self.1.get() + 1);
+                Self(self.0, self.1)
+            }
+        }
+
+        impl<'a> core::ops::Mul for &Counted<'a> {
+            type Output = Counted<'a>;
+
+            fn mul(self, rhs: Self) -> Counted<'a> {
+                Counted(self.0 * rhs.0, self.1)
+            }
+        }
+
+        impl core::ops::Add for Counted<'_> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0, self.1)
+            }
+        }
+
+        impl core::ops::Sub for Counted<'_> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0, self.1)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let a = NorthEastDown::new(
+            Counted(1, &clones),
+            Counted(2, &clones),
+            Counted(3, &clones),
+        );
+        let b = NorthEastDown::new(
+            Counted(4, &clones),
+            Counted(5, &clones),
+            Counted(6, &clones),
+        );
+
+        // Components are multiplied by reference, so no clones are needed.
+        assert_eq!(a.norm_sq().0, 14);
+        assert_eq!(clones.replace(0), 0);
+
+        assert_eq!(a.dot(&b).0, 32);
+        assert_eq!(clones.replace(0), 0);
+
+        assert_eq!(a.cross(&b).0.map(|c| c.0), [-3, 6, -3]);
+        assert_eq!(clones.replace(0), 0);
+    }
+
+    #[test]
+    fn const_conversions() {
+        const NED: NorthEastDown<f32> = NorthEastDown([1.0, 2.0, 3.0]);
+        const EDN: EastDownNorth<f32> = NED.to_east_down_north();
+        assert_eq!(EDN, EastDownNorth::new(2.0, 3.0, 1.0));
+
+        const NED_I8: NorthEastDown<i8> = NorthEastDown([i8::MIN, 2, 3]);
+        const SWU: SouthWestUp<i8> = NED_I8.to_south_west_up();
+        const UNE: UpNorthEast<i8> = NED_I8.to_up_north_east();
+        assert_eq!(SWU, SouthWestUp::new(i8::MAX, -2, -3));
+        assert_eq!(UNE, UpNorthEast::from(NED_I8));
+        assert_eq!(NED_I8.to_east_north_up(), NED_I8.to_enu());
+    }
+
+    #[test]
+    fn sum_and_centroid() {
+        let values = [
+            NorthEastDown::new(1.0, 2.0, 3.0),
+            NorthEastDown::new(2.0, 4.0, 6.0),
+            NorthEastDown::new(3.0, 6.0, 0.0),
+        ];
+        let sum: NorthEastDown<f64> = values.iter().sum();
+        assert_eq!(sum, NorthEastDown::new(6.0, 12.0, 9.0));
+        assert_eq!(values.into_iter().sum::<NorthEastDown<f64>>(), sum);
+
+        assert_eq!(
+            NorthEastDown::centroid(values),
+            Some(NorthEastDown::new(2.0, 4.0, 3.0))
+        );
+        assert_eq!(NorthEastDown::<f64>::centroid([]), None);
+    }
+
+    #[test]
+    fn checked_saturating_cross() {
+        let a = NorthEastDown::new(1i16, 2, 3);
+        let b = NorthEastDown::new(4, 5, 6);
+        assert_eq!(a.checked_cross(&b), Some(a.cross(&b)));
+        assert_eq!(a.saturating_cross(&b), a.cross(&b));
+
+        let a = NorthEastDown::new(0i16, i16::MAX, 2);
+        let b = NorthEastDown::new(-2, 0, i16::MAX);
+        assert_eq!(a.checked_cross(&b), None);
+        assert_eq!(
+            a.saturating_cross(&b),
+            NorthEastDown::new(i16::MAX, -4, i16::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn normalize_or() {
+        let fallback = NorthEastDown::new(0.0, 0.0, 1.0);
+        let zero = NorthEastDown::new(0.0, 0.0, 0.0);
+        assert_eq!(zero.normalize_or(fallback, 1e-6), fallback);
+
+        let tiny = NorthEastDown::new(1e-9, 0.0, 0.0);
+        assert_eq!(tiny.normalize_or(fallback, 1e-6), fallback);
+
+        let nan = NorthEastDown::new(f64::NAN, 0.0, 0.0);
+        assert_eq!(nan.normalize_or(fallback, 1e-6), fallback);
+
+        let ned = NorthEastDown::new(3.0, 0.0, 4.0);
+        assert_eq!(
+            ned.normalize_or(fallback, 1e-6),
+            NorthEastDown::new(0.6, 0.0, 0.8)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn as_cow() {
+        use alloc::borrow::Cow;
+
+        /// A `Clone`-only element type.
+        #[derive(Debug, Clone, PartialEq)]
+        struct Value(i32);
+
+        impl SaturatingNeg for Value {
+            type Output = Self;
+
+            fn saturating_neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+
+        let ned = NorthEastDown::new(Value(1), Value(2), Value(3));
+        assert!(matches!(ned.as_ned_cow(), Cow::Borrowed(borrowed) if *borrowed == ned));
+
+        let enu = ned.as_enu_cow();
+        assert!(matches!(enu, Cow::Owned(_)));
+        assert_eq!(*enu, EastNorthUp::new(Value(2), Value(1), Value(-3)));
+        assert!(matches!(enu.as_enu_cow(), Cow::Borrowed(_)));
+        assert_eq!(*enu.as_ned_cow(), ned);
+    }
+
+    #[test]
+    fn components_mut() {
+        let mut ned = NorthEastDown::new(1, 2, 3);
+        let (x, _, z) = ned.components_mut();
+        core::mem::swap(x, z);
+        assert_eq!(ned, NorthEastDown::new(3, 2, 1));
+        assert_eq!(ned.components(), (&3, &2, &1));
+    }
+
+    #[test]
+    fn from_ned_enu_array() {
+        let wus = WestUpSouth::from_ned_array([1.0, 2.0, 3.0]);
+        assert_eq!(wus, WestUpSouth::new(-2.0, -3.0, -1.0));
+        assert_eq!(wus.to_ned(), NorthEastDown::new(1.0, 2.0, 3.0));
+
+        let ned = NorthEastDown::from_enu_array([1.0, 2.0, 3.0]);
+        assert_eq!(ned, NorthEastDown::new(2.0, 1.0, -3.0));
+        assert_eq!(
+            EastNorthUp::from_enu_array([1.0, 2.0, 3.0]),
+            EastNorthUp::new(1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let values = [1, 2, 3, 4];
+        assert!(matches!(
+            NorthEastDown::try_from(&values[..2]),
+            Err(ParseCoordinateFrameError::WrongLength {
+                expected: 3,
+                got: 2
+            })
+        ));
+        assert_eq!(
+            NorthEastDown::try_from(&values[..3]).ok(),
+            Some(NorthEastDown::new(1, 2, 3))
+        );
+        assert!(matches!(
+            NorthEastDown::try_from(&values[..]),
+            Err(ParseCoordinateFrameError::WrongLength {
+                expected: 3,
+                got: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn debug_validate() {
+        assert_eq!(NorthEastDown::new(1.0, 2.0, 3.0).debug_validate(), Ok(()));
+        assert!(NorthEastDown::new(1.0, f64::NAN, 3.0)
+            .debug_validate()
+            .is_err());
+        assert!(NorthEastDown::new(f32::INFINITY, 2.0, 3.0)
+            .debug_validate()
+            .is_err());
+        assert_eq!(NorthEastDown::new(1, 2, 3).debug_validate(), Ok(()));
+    }
+
+    #[test]
+    fn slice() {
+        let ned = NorthEastDown::new(1, 2, 3);
+        assert_eq!(ned.slice(0..2), &[1, 2]);
+        assert_eq!(ned.slice(1..3), &[2, 3]);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(ned.to_homogeneous(), [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(NorthEastDown::from_homogeneous([2.0, 4.0, 6.0, 2.0]), ned);
+        assert_eq!(
+            NorthEastDown::from_homogeneous_checked([2.0, 4.0, 6.0, 2.0]),
+            Some(ned)
+        );
+        assert_eq!(
+            NorthEastDown::from_homogeneous_checked([2.0, 4.0, 6.0, 0.0]),
+            None
+        );
+        assert_eq!(NorthEastDown::from_homogeneous_checked([2, 4, 6, 0]), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn from_str() {
+        let specials = [CoordinateFrameType::Other, CoordinateFrameType::Undefined];
+        for &frame in CoordinateFrameType::PHYSICAL.iter().chain(specials.iter()) {
+            let name = frame.to_string();
+            let parsed: CoordinateFrameType = name.parse().expect("valid variant name");
+            assert_eq!(parsed, frame);
+            assert_eq!(parsed.to_string(), name);
+        }
+
+        assert!(matches!(
+            "NorthEastSouth".parse::<CoordinateFrameType>(),
+            Err(ParseCoordinateFrameError::UnknownVariant)
+        ));
+    }
+
+    #[test]
+    fn physical_frames() {
+        const PHYSICAL: &[CoordinateFrameType] = CoordinateFrameType::PHYSICAL;
+        assert_eq!(PHYSICAL.len(), 48);
+        assert!(!PHYSICAL.contains(&CoordinateFrameType::Other));
+        assert!(!PHYSICAL.contains(&CoordinateFrameType::Undefined));
+        assert_eq!(PHYSICAL[0], CoordinateFrameType::NorthEastDown);
+    }
+
+    #[test]
+    fn frame_type_components() {
+        use Direction::*;
+        assert_eq!(
+            CoordinateFrameType::NorthEastDown.components(),
+            Some([North, East, Down])
+        );
+        assert_eq!(
+            CoordinateFrameType::WestUpSouth.components(),
+            Some([West, Up, South])
+        );
+        assert_eq!(CoordinateFrameType::Other.components(), None);
+        assert_eq!(CoordinateFrameType::Undefined.components(), None);
+
+        assert_eq!(
+            CoordinateFrameType::NorthEastDown.is_right_handed(),
+            Some(true)
+        );
+        assert_eq!(
+            CoordinateFrameType::NorthEastUp.is_right_handed(),
+            Some(false)
+        );
+        assert_eq!(CoordinateFrameType::Undefined.is_right_handed(), None);
+
+        // Right-handed frames map onto NED by a proper rotation, i.e. with determinant 1.
+        for &frame in CoordinateFrameType::PHYSICAL {
+            let m =
+                CoordinateFrameType::conversion_matrix(frame, CoordinateFrameType::NorthEastDown)
+                    .expect("physical frame");
+            let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+            assert_eq!(frame.is_right_handed(), Some(determinant == 1));
+        }
+    }
+
+    #[test]
+    fn frame_type_flipped() {
+        assert_eq!(
+            CoordinateFrameType::NorthEastDown.flipped(),
+            CoordinateFrameType::SouthWestUp
+        );
+        assert_eq!(
+            CoordinateFrameType::Other.flipped(),
+            CoordinateFrameType::Other
+        );
+        assert_eq!(
+            CoordinateFrameType::Undefined.flipped(),
+            CoordinateFrameType::Undefined
+        );
+
+        for &frame in CoordinateFrameType::all() {
+            assert_ne!(frame.flipped(), frame);
+            assert_eq!(frame.flipped().flipped(), frame);
+        }
+    }
+
+    #[test]
+    fn frame_type_name() {
+        const NAME: &str = CoordinateFrameType::NorthEastDown.name();
+        assert_eq!(NAME, "NorthEastDown");
+        assert_eq!(CoordinateFrameType::Undefined.name(), "Undefined");
+    }
+
+    #[test]
+    fn const_lookups() {
+        const NED: usize = CoordinateFrameType::NorthEastDown as usize;
+        const HANDEDNESS: Handedness = crate::handedness_of(NED);
+        const VERTICAL: Axis = crate::vertical_axis_of(NED);
+        assert_eq!(HANDEDNESS, Handedness::Right);
+        assert_eq!(VERTICAL, Axis::Z);
+
+        assert_eq!(
+            crate::handedness_of(CoordinateFrameType::NorthEastUp as usize),
+            Handedness::Left
+        );
+        assert_eq!(
+            crate::vertical_axis_of(CoordinateFrameType::UpSouthWest as usize),
+            Axis::X
+        );
+
+        for (index, frame) in CoordinateFrameType::PHYSICAL.iter().enumerate() {
+            let right_handed = crate::handedness_of(index) == Handedness::Right;
+            assert_eq!(frame.is_right_handed(), Some(right_handed));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_lookups_out_of_range() {
+        crate::handedness_of(CoordinateFrameType::PHYSICAL.len());
+    }
+
+    #[test]
+    fn frame_rotation() {
+        use CoordinateFrameType::*;
+        let ned_to_enu = FrameRotation::between(NorthEastDown, EastNorthUp).unwrap();
+        let ned = crate::NorthEastDown::new(1, 2, 3);
+        assert_eq!(ned_to_enu.apply(ned.0), ned.to_enu().0);
+
+        let inverse = ned_to_enu.inverse();
+        assert_eq!(inverse.apply(ned.to_enu().0), ned.0);
+        assert_eq!(ned_to_enu.compose(&inverse), FrameRotation::IDENTITY);
+        assert_eq!(inverse.compose(&ned_to_enu), FrameRotation::IDENTITY);
+
+        // Composition chains frame changes.
+        let enu_to_wus = FrameRotation::between(EastNorthUp, WestUpSouth).unwrap();
+        let ned_to_wus = FrameRotation::between(NorthEastDown, WestUpSouth).unwrap();
+        assert_eq!(ned_to_enu.compose(&enu_to_wus), ned_to_wus);
+
+        assert_eq!(FrameRotation::between(NorthEastDown, Other), None);
+    }
+
+    #[test]
+    fn all_frames() {
+        assert_eq!(CoordinateFrameType::all().len(), 48);
+        for &frame in CoordinateFrameType::all() {
+            assert_eq!(CoordinateFrameType::try_from(frame as u8).ok(), Some(frame));
+        }
+
+        let all = CoordinateFrameType::all_including_special();
+        assert_eq!(all.len(), 50);
+        assert!(all.contains(&CoordinateFrameType::Other));
+        assert!(all.contains(&CoordinateFrameType::Undefined));
+        for &frame in all {
+            assert_eq!(CoordinateFrameType::try_from(frame as u8).ok(), Some(frame));
+        }
+    }
+
+    #[test]
+    fn express_in_all() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        let mut count = 0;
+        for (frame, values) in crate::express_in_all(ned) {
+            count += 1;
+            match frame {
+                CoordinateFrameType::NorthEastDown => assert_eq!(values, [1.0, 2.0, 3.0]),
+                CoordinateFrameType::EastNorthUp => assert_eq!(values, [2.0, 1.0, -3.0]),
+                CoordinateFrameType::SouthWestUp => assert_eq!(values, [-1.0, -2.0, -3.0]),
+                _ => {}
+            }
+        }
+        assert_eq!(count, 48);
+    }
+
     #[test]
     fn ned_to_enu() {
         let ned = NorthEastDown([1.0, 2.0, 3.0]);
@@ -231,6 +1390,243 @@ mod tests {
         assert_eq!(enu.0, [2.0, 1.0, -3.0]);
     }
 
+    #[test]
+    fn exhaustive_conversions() {
+        let mut count = 0;
+        crate::for_each_conversion_pair([1, -2, 3], |from, to, direct, pivot| {
+            count += 1;
+            assert_eq!(direct, pivot, "{from} -> {to}");
+        });
+        assert_eq!(count, 48 * 48);
+    }
+
+    #[test]
+    fn conversion_matrix() {
+        const NED_TO_ENU: Option<[[i8; 3]; 3]> = CoordinateFrameType::conversion_matrix(
+            CoordinateFrameType::NorthEastDown,
+            CoordinateFrameType::EastNorthUp,
+        );
+        assert_eq!(NED_TO_ENU, Some([[0, 1, 0], [1, 0, 0], [0, 0, -1]]));
+
+        let values = [1, -2, 3];
+        crate::for_each_conversion_pair(values, |from, to, direct, _| {
+            let matrix = CoordinateFrameType::conversion_matrix(from, to).expect("physical frames");
+            let transformed =
+                matrix.map(|row| (0..3).map(|j| i32::from(row[j]) * values[j]).sum::<i32>());
+            assert_eq!(transformed, direct, "{from} -> {to}");
+        });
+
+        assert_eq!(
+            CoordinateFrameType::conversion_matrix(
+                CoordinateFrameType::Other,
+                CoordinateFrameType::NorthEastDown
+            ),
+            None
+        );
+        assert_eq!(
+            CoordinateFrameType::conversion_matrix(
+                CoordinateFrameType::NorthEastDown,
+                CoordinateFrameType::Undefined
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn axis_permutation() {
+        assert_eq!(
+            <NorthEastDown<f32> as AxisPermutation>::TO_NED,
+            ([0, 1, 2], [1, 1, 1])
+        );
+        assert_eq!(
+            <EastNorthUp<f32> as AxisPermutation>::TO_NED,
+            ([1, 0, 2], [1, 1, -1])
+        );
+
+        let enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        let (indices, signs) = <EastNorthUp<f64> as AxisPermutation>::TO_NED;
+        let ned = NorthEastDown::new(
+            enu[indices[0]] * f64::from(signs[0]),
+            enu[indices[1]] * f64::from(signs[1]),
+            enu[indices[2]] * f64::from(signs[2]),
+        );
+        assert_eq!(ned, enu.to_ned());
+    }
+
+    #[test]
+    fn to_ned_with_correction() {
+        let enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(enu.to_ned_with_correction(&identity), enu.to_ned());
+
+        // 90° about the down axis.
+        let yaw = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(
+            enu.to_ned_with_correction(&yaw),
+            NorthEastDown::new(-1.0, 2.0, -3.0)
+        );
+    }
+
+    #[test]
+    fn to_frame_checked_vertical() {
+        let ned = NorthEastDown::new(1, 2, 3);
+        let end: EastNorthDown<_> = ned.to_frame_checked_vertical().expect("down stays down");
+        assert_eq!(end, EastNorthDown::new(2, 1, 3));
+
+        assert!(matches!(
+            ned.to_frame_checked_vertical::<EastNorthUp<_>>(),
+            Err(ParseCoordinateFrameError::VerticalMismatch)
+        ));
+    }
+
+    #[test]
+    fn to_ned_copy_only() {
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        struct Meters(i32);
+
+        let end = EastNorthDown::new(Meters(1), Meters(2), Meters(3));
+        assert_eq!(
+            end.to_ned(),
+            NorthEastDown::new(Meters(2), Meters(1), Meters(3))
+        );
+
+        let ned = NorthEastDown::new(Meters(1), Meters(2), Meters(3));
+        assert_eq!(ned.to_ned(), ned);
+
+        let enu = EastNorthUp::new(Meters(1), Meters(2), Meters(3));
+        assert_eq!(enu.to_enu(), enu);
+    }
+
+    #[test]
+    fn into_ned_enu() {
+        #[derive(Debug, PartialEq)]
+        struct Value(i32);
+
+        impl SaturatingNeg for Value {
+            type Output = Self;
+
+            fn saturating_neg(self) -> Self {
+                Value(self.0.saturating_neg())
+            }
+        }
+
+        let enu = EastNorthUp::new(Value(1), Value(2), Value(3));
+        let ned = enu.into_ned();
+        assert_eq!(ned, NorthEastDown::new(Value(2), Value(1), Value(-3)));
+
+        let enu = CoordinateFrame::into_enu(ned);
+        assert_eq!(enu, EastNorthUp::new(Value(1), Value(2), Value(3)));
+    }
+
+    #[test]
+    fn scalar_mul_left() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(2.0 * ned, ned * 2.0);
+        assert_eq!(2.0 * ned, NorthEastDown::new(2.0, 4.0, 6.0));
+        assert_eq!(3 * EastNorthUp::new(1_u8, 2, 3), EastNorthUp::new(3, 6, 9));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)] // The borrowed forms are what is under test.
+    fn borrowed_rhs() {
+        let a = NorthEastDown::new(5, 7, 9);
+        let b = NorthEastDown::new(1, 2, 3);
+        assert_eq!(&a - &b, NorthEastDown::new(4, 5, 6));
+        assert_eq!(&a + &b, NorthEastDown::new(6, 9, 12));
+        assert_eq!(a - &b, NorthEastDown::new(4, 5, 6));
+        assert_eq!(a + &b, NorthEastDown::new(6, 9, 12));
+    }
+
+    #[test]
+    fn add_tuple() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(ned + (1.0, 0.0, -1.0), NorthEastDown::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn sub_tuple() {
+        let enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        assert_eq!(enu - (1.0, 1.0, 1.0), EastNorthUp::new(0.0, 1.0, 2.0));
+    }
+
+    #[test]
+    fn assign_tuple() {
+        let mut frame = NorthEastDown::new(1.0, 2.0, 3.0);
+        frame += (1.0, 0.0, -1.0);
+        assert_eq!(frame, NorthEastDown::new(2.0, 2.0, 2.0));
+
+        frame -= (2.0, 1.0, 0.5);
+        assert_eq!(frame, NorthEastDown::new(0.0, 1.0, 1.5));
+    }
+
+    #[test]
+    fn mul_array() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(ned * [2.0, 0.5, 1.0], NorthEastDown::new(2.0, 1.0, 3.0));
+    }
+
+    #[test]
+    fn hadamard() {
+        let raw = NorthEastDown::new(2.0, 4.0, 6.0);
+        let gain = NorthEastDown::new(0.5, 2.0, -1.0);
+        assert_eq!(raw * gain, NorthEastDown::new(1.0, 8.0, -6.0));
+        assert_eq!(raw / gain, NorthEastDown::new(4.0, 2.0, -6.0));
+
+        // Scalar operations still resolve.
+        assert_eq!(raw * 2.0, NorthEastDown::new(4.0, 8.0, 12.0));
+        assert_eq!(raw / 2.0, NorthEastDown::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn div_element_wise() {
+        let scaled = NorthEastDown::new(4, 10, 18);
+        let factors = NorthEastDown::new(2, 5, 6);
+        assert_eq!(scaled / factors, NorthEastDown::new(2, 2, 3));
+
+        let divided = NorthEastDown::new(1.0, -1.0, 0.0) / NorthEastDown::new(0.0, 0.0, 0.0);
+        assert_eq!(divided.north(), f64::INFINITY);
+        assert_eq!(divided.east(), f64::NEG_INFINITY);
+        assert!(divided.down().is_nan());
+    }
+
+    #[test]
+    fn neg() {
+        let ned = NorthEastDown::new(1.0, -2.0, 3.0);
+        let negated = -&ned;
+        assert_eq!(negated, NorthEastDown::new(-1.0, 2.0, -3.0));
+        assert_eq!(ned, NorthEastDown::new(1.0, -2.0, 3.0));
+        assert_eq!(-ned, negated);
+    }
+
+    #[test]
+    fn vertical_axis() {
+        assert_eq!(NorthEastDown::<f32>::VERTICAL_AXIS, Axis::Z);
+        assert_eq!(NorthEastDown::<f32>::VERTICAL_DIRECTION, Direction::Down);
+        assert_eq!(UpSouthWest::<f32>::VERTICAL_AXIS, Axis::X);
+        assert_eq!(UpSouthWest::<f32>::VERTICAL_DIRECTION, Direction::Up);
+        assert_eq!(EastUpNorth::<f32>::VERTICAL_AXIS, Axis::Y);
+    }
+
+    #[test]
+    fn cross_sign() {
+        const NED: i8 = NorthEastDown::<f32>::CROSS_SIGN;
+        const NEU: i8 = NorthEastUp::<f32>::CROSS_SIGN;
+        assert_eq!(NED, 1);
+        assert_eq!(NEU, -1);
+    }
+
+    #[test]
+    fn specialized_ned_enu() {
+        let ned = NorthEastDown::new(1.0_f32, -2.0, 3.0);
+        assert_eq!(crate::ned_to_enu(ned), ned.to_enu());
+        assert_eq!(crate::ned_to_enu(ned), EastNorthUp::from(ned));
+        assert_eq!(crate::enu_to_ned(crate::ned_to_enu(ned)), ned);
+
+        let enu = EastNorthUp::new(1.0_f32, -2.0, 3.0);
+        assert_eq!(crate::enu_to_ned(enu), enu.to_ned());
+        assert_eq!(crate::enu_to_ned(enu), NorthEastDown::from(enu));
+    }
+
     #[test]
     fn flip() {
         let ned = NorthEastDown([1.0, 2.0, 3.0]);
@@ -238,6 +1634,75 @@ mod tests {
         assert_eq!(swu.0, [-1.0, -2.0, -3.0]);
     }
 
+    #[test]
+    fn mirror() {
+        let ned = NorthEastDown([1.0, 2.0, 3.0]);
+
+        let neu: NorthEastUp<_> = ned.mirror_vertical();
+        assert_eq!(neu.0, [1.0, 2.0, -3.0]);
+        assert_eq!(neu.coordinate_frame(), CoordinateFrameType::NorthEastUp);
+        assert_ne!(neu.right_handed(), ned.right_handed());
+
+        let sed: SouthEastDown<_> = ned.mirror_longitudinal();
+        assert_eq!(sed.0, [-1.0, 2.0, 3.0]);
+        assert_ne!(sed.right_handed(), ned.right_handed());
+
+        let nwd: NorthWestDown<_> = ned.mirror_lateral();
+        assert_eq!(nwd.0, [1.0, -2.0, 3.0]);
+        assert_ne!(nwd.right_handed(), ned.right_handed());
+    }
+
+    #[test]
+    fn direction() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        assert_eq!(ned[Direction::North], 1.0);
+        assert_eq!(ned[Direction::East], 2.0);
+        assert_eq!(ned[Direction::Down], 3.0);
+
+        assert_eq!(ned.component(Direction::Up), -3.0);
+        assert_eq!(ned.component(Direction::South), -1.0);
+        assert_eq!(ned.component(Direction::West), -2.0);
+        assert_eq!(ned.component(Direction::Down), 3.0);
+
+        // Numeric indexing is unaffected.
+        let mut ned = ned;
+        ned[2] = 4.0;
+        assert_eq!(ned[0], 1.0);
+        assert_eq!(ned[1..], [2.0, 4.0]);
+    }
+
+    #[test]
+    fn axis_index() {
+        let mut enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        assert_eq!(enu[Axis::X], 1.0);
+        assert_eq!(enu[Axis::Y], 2.0);
+        assert_eq!(enu[Axis::Z], 3.0);
+
+        enu[Axis::Z] = 4.0;
+        assert_eq!(enu.up(), 4.0);
+    }
+
+    #[test]
+    fn direction_get() {
+        let enu = EastNorthUp::new(1.0, 2.0, 3.0);
+        assert_eq!(enu.get(Direction::North), Some(2.0));
+        assert_eq!(enu.get(Direction::Down), Some(-3.0));
+
+        let dynamic = DynamicFrame::from(enu);
+        assert_eq!(dynamic.get(Direction::West), Some(-1.0));
+        assert_eq!(dynamic.get(Direction::Up), Some(3.0));
+
+        let other = DynamicFrame::new(CoordinateFrameType::Other, [1.0, 2.0, 3.0]);
+        assert_eq!(other.get(Direction::North), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn direction_derived_index() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        let _ = ned[Direction::Up];
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn test_permutations() {
@@ -270,6 +1735,149 @@ mod tests {
         assert_eq!(permutations.len(), 48);
     }
 
+    #[test]
+    #[cfg(feature = "approx")]
+    fn approx_eq() {
+        use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
+
+        let ned = NorthEastDown::new(0.1 + 0.2, 1.0 / 3.0, -2.0);
+        let roundtrip = ned.to_enu().to_ned();
+        let expected = NorthEastDown::new(0.3, 0.333_333_333_333_333_3, -2.0);
+        assert_ne!(roundtrip, expected);
+        assert_abs_diff_eq!(roundtrip, expected);
+        assert_relative_eq!(roundtrip, expected);
+        assert_ulps_eq!(roundtrip, expected);
+        assert_abs_diff_eq!(roundtrip, NorthEastDown::new(0.3, 0.3, -2.0), epsilon = 0.1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip() {
+        let ned = NorthEastDown::new(1.0, 2.0, 3.0);
+        let json = serde_json::to_string(&ned).expect("serialization succeeds");
+        assert_eq!(json, "[1.0,2.0,3.0]");
+        let deserialized: NorthEastDown<f64> =
+            serde_json::from_str(&json).expect("deserialization succeeds");
+        assert_eq!(deserialized, ned);
+
+        let frame = CoordinateFrameType::EastNorthUp;
+        let json = serde_json::to_string(&frame).expect("serialization succeeds");
+        assert_eq!(json, "9");
+        let deserialized: CoordinateFrameType =
+            serde_json::from_str(&json).expect("deserialization succeeds");
+        assert_eq!(deserialized, frame);
+
+        assert!(serde_json::from_str::<CoordinateFrameType>("200").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "micromath")]
+    fn micromath_u8x3_narrowing() {
+        let ned = NorthEastDown::new(1_u32, 255, 256);
+        let saturated = ned.to_u8x3_saturating();
+        assert_eq!((saturated.x, saturated.y, saturated.z), (1, 255, 255));
+
+        let wrapped = ned.to_u8x3_wrapping();
+        assert_eq!((wrapped.x, wrapped.y, wrapped.z), (1, 255, 0));
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn glam_roundtrip() {
+        let ned = NorthEastDown::from(glam::Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(ned, NorthEastDown::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            glam::Vec3::from(ned.to_enu()),
+            glam::Vec3::new(2.0, 1.0, -3.0)
+        );
+
+        let ned = NorthEastDown::from(glam::Vec3A::new(1.0, 2.0, 3.0));
+        assert_eq!(glam::Vec3A::from(ned), glam::Vec3A::new(1.0, 2.0, 3.0));
+
+        let ned = NorthEastDown::from(glam::IVec3::new(1, 2, 3));
+        assert_eq!(glam::IVec3::from(ned), glam::IVec3::new(1, 2, 3));
+
+        let ned = NorthEastDown::from(glam::DVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(glam::DVec3::from(ned), glam::DVec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    #[cfg(feature = "mint")]
+    fn mint_roundtrip() {
+        let ned = NorthEastDown::from(mint::Vector3 { x: 1, y: 2, z: 3 });
+        assert_eq!(ned, NorthEastDown::new(1, 2, 3));
+        let vector: mint::Vector3<_> = ned.to_enu().into();
+        assert_eq!(vector, mint::Vector3 { x: 2, y: 1, z: -3 });
+
+        let ned = NorthEastDown::from(mint::Point3 { x: 1, y: 2, z: 3 });
+        let point: mint::Point3<_> = ned.into();
+        assert_eq!(point, mint::Point3 { x: 1, y: 2, z: 3 });
+    }
+
+    #[test]
+    fn to_ned_with_matrix() {
+        let frame = WestUpNorth::new(1, 2, 3);
+        let (ned, matrix) = frame.to_ned_with_matrix();
+        assert_eq!(ned, frame.to_ned());
+
+        let [x, y, z] = frame.0;
+        let applied = matrix.map(|row| row[0] * x + row[1] * y + row[2] * z);
+        assert_eq!(applied, ned.0);
+        assert_eq!(applied, [3, -1, -2]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn bytemuck_cast_slice() {
+        let frames = [
+            NorthEastDown::new(1.0f32, 2.0, 3.0),
+            NorthEastDown::new(4.0, 5.0, 6.0),
+        ];
+        let bytes: &[u8] = bytemuck::cast_slice(&frames);
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(bytes[..4], 1.0f32.to_ne_bytes());
+
+        let back: &[NorthEastDown<f32>] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, frames);
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half_precision() {
+        use half::{bf16, f16};
+
+        let ned = NorthEastDown::new(f16::from_f32(1.0), f16::from_f32(2.0), f16::from_f32(3.0));
+        let enu = ned.to_enu();
+        assert_eq!(enu.up(), f16::from_f32(-3.0));
+        assert_eq!(enu.east(), f16::from_f32(2.0));
+        assert_eq!(ned.norm_sq(), f16::from_f32(14.0));
+        assert_eq!(
+            NorthEastDown::<f16>::z_axis(),
+            [f16::ZERO, f16::ZERO, f16::ONE]
+        );
+
+        let ned = NorthEastDown::new(bf16::ONE, bf16::ZERO, bf16::ONE);
+        assert_eq!(ned.to_enu().up(), -bf16::ONE);
+    }
+
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn fixed_point() {
+        use fixed::types::{I16F16, I2F30};
+
+        let ned = NorthEastDown::new(I16F16::MIN, I16F16::from_num(2.5), I16F16::ZERO);
+        let swu = SouthWestUp::from(ned);
+        assert_eq!(swu.south(), I16F16::MAX);
+        assert_eq!(swu.west(), I16F16::from_num(-2.5));
+        assert_eq!(swu.up(), I16F16::ZERO);
+
+        assert_eq!(ned.axis_in_ned(Axis::X).north(), I16F16::ONE);
+
+        // Two integer bits are the fewest that can represent one.
+        let zero = NorthEastDown::new(I2F30::ZERO, I2F30::ZERO, I2F30::ZERO);
+        assert_eq!(zero.axis_in_ned(Axis::X).north(), I2F30::ONE);
+    }
+
     #[test]
     #[cfg(feature = "nalgebra")]
     fn nalgebra_from_point3() {
@@ -289,4 +1897,45 @@ mod tests {
         assert_eq!(point.y, 1.0);
         assert_eq!(point.z, -3.0);
     }
+
+    #[test]
+    #[cfg(feature = "nalgebra")]
+    fn nalgebra_rotated() {
+        // 90° about the down axis.
+        let rotation = nalgebra::Rotation3::from_matrix_unchecked(nalgebra::Matrix3::new(
+            0.0, -1.0, 0.0, //
+            1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0,
+        ));
+
+        let ned = NorthEastDown::new(1.0, 0.0, 2.0);
+        let rotated = ned.rotated(&rotation);
+        assert_eq!(rotated.north(), 0.0);
+        assert_eq!(rotated.east(), 1.0);
+        assert_eq!(rotated.down(), 2.0);
+
+        let multiplied = rotation * ned;
+        assert_eq!(multiplied, rotated);
+    }
+
+    #[test]
+    #[cfg(all(feature = "nalgebra", feature = "std"))]
+    fn nalgebra_rotate_quaternion() {
+        // 90° about the down axis.
+        let q = nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::new(
+            core::f64::consts::FRAC_1_SQRT_2,
+            0.0,
+            0.0,
+            core::f64::consts::FRAC_1_SQRT_2,
+        ));
+
+        let ned = NorthEastDown::new(1.0, 0.0, 2.0);
+        let [x, y, z] = ned.rotate(&q);
+        assert!(x.abs() < 1e-12);
+        assert!((y - 1.0).abs() < 1e-12);
+        assert!((z - 2.0).abs() < 1e-12);
+
+        let other = DynamicFrame::new(CoordinateFrameType::Other, [x, y, z]);
+        assert_eq!(other.frame(), CoordinateFrameType::Other);
+    }
 }
@@ -0,0 +1,101 @@
+use crate::{CoordinateFrameType, SaturatingNeg};
+
+/// A change of coordinate frame, expressed as a signed permutation matrix.
+///
+/// Since every conversion between the physical coordinate frames only permutes and negates
+/// axes, these rotations are exact and can be composed and inverted without loss.
+///
+/// ## Example
+/// ```
+/// use coordinate_frame::{CoordinateFrameType, FrameRotation, NorthEastDown};
+///
+/// let rotation = FrameRotation::between(
+///     CoordinateFrameType::NorthEastDown,
+///     CoordinateFrameType::EastNorthUp,
+/// )
+/// .unwrap();
+///
+/// let ned = NorthEastDown::new(1, 2, 3);
+/// assert_eq!(rotation.apply(*ned), *ned.to_enu());
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct FrameRotation {
+    /// The row-major signed permutation matrix.
+    matrix: [[i8; 3]; 3],
+}
+
+impl FrameRotation {
+    /// The identity rotation, i.e. no change of frame.
+    pub const IDENTITY: Self = Self {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// Constructs the rotation that maps coordinates in the `from` frame into the `to` frame.
+    ///
+    /// Returns `None` if either frame type is [`Other`](CoordinateFrameType::Other) or
+    /// [`Undefined`](CoordinateFrameType::Undefined).
+    pub const fn between(from: CoordinateFrameType, to: CoordinateFrameType) -> Option<Self> {
+        match CoordinateFrameType::conversion_matrix(from, to) {
+            Some(matrix) => Some(Self { matrix }),
+            None => None,
+        }
+    }
+
+    /// Returns the row-major signed permutation matrix of this rotation.
+    pub const fn matrix(&self) -> [[i8; 3]; 3] {
+        self.matrix
+    }
+
+    /// Returns the rotation that first applies `self` and then `next`.
+    pub const fn compose(&self, next: &Self) -> Self {
+        let mut matrix = [[0; 3]; 3];
+        let mut i = 0;
+        while i < 3 {
+            let mut j = 0;
+            while j < 3 {
+                let mut k = 0;
+                while k < 3 {
+                    matrix[i][j] += next.matrix[i][k] * self.matrix[k][j];
+                    k += 1;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        Self { matrix }
+    }
+
+    /// Returns the inverse rotation, i.e. the transposed matrix.
+    pub const fn inverse(&self) -> Self {
+        let m = self.matrix;
+        Self {
+            matrix: [
+                [m[0][0], m[1][0], m[2][0]],
+                [m[0][1], m[1][1], m[2][1]],
+                [m[0][2], m[1][2], m[2][2]],
+            ],
+        }
+    }
+
+    /// Applies this rotation to the specified components.
+    pub fn apply<T>(&self, data: [T; 3]) -> [T; 3]
+    where
+        T: Copy + SaturatingNeg<Output = T>,
+    {
+        // Each row has exactly one non-zero entry.
+        self.matrix.map(|row| {
+            let column = row.iter().position(|&sign| sign != 0).unwrap_or(0);
+            if row[column] < 0 {
+                data[column].saturating_neg()
+            } else {
+                data[column]
+            }
+        })
+    }
+}
+
+impl Default for FrameRotation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
@@ -0,0 +1,44 @@
+//! Overflow-aware summation of integer coordinates.
+
+use crate::{CheckedArithmetic, CoordinateFrame, SaturatingArithmetic, ZeroOne};
+
+/// Sums all coordinates of the iterator component-wise, returning `None` on overflow.
+///
+/// An empty iterator sums to zero.
+pub fn checked_sum<I, F>(iter: I) -> Option<F>
+where
+    I: IntoIterator<Item = F>,
+    F: CoordinateFrame + From<[F::Type; 3]> + Into<[F::Type; 3]>,
+    F::Type: ZeroOne<Output = F::Type> + CheckedArithmetic<Output = F::Type>,
+{
+    let mut sum = [F::Type::zero(), F::Type::zero(), F::Type::zero()];
+    for item in iter {
+        let [x, y, z] = item.into();
+        let [sx, sy, sz] = sum;
+        sum = [sx.checked_add(x)?, sy.checked_add(y)?, sz.checked_add(z)?];
+    }
+    Some(F::from(sum))
+}
+
+/// Sums all coordinates of the iterator component-wise, saturating at the numeric bounds
+/// instead of overflowing.
+///
+/// An empty iterator sums to zero.
+pub fn saturating_sum<I, F>(iter: I) -> F
+where
+    I: IntoIterator<Item = F>,
+    F: CoordinateFrame + From<[F::Type; 3]> + Into<[F::Type; 3]>,
+    F::Type: ZeroOne<Output = F::Type> + SaturatingArithmetic<Output = F::Type>,
+{
+    let mut sum = [F::Type::zero(), F::Type::zero(), F::Type::zero()];
+    for item in iter {
+        let [x, y, z] = item.into();
+        let [sx, sy, sz] = sum;
+        sum = [
+            sx.saturating_add(x),
+            sy.saturating_add(y),
+            sz.saturating_add(z),
+        ];
+    }
+    F::from(sum)
+}
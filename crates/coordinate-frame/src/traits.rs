@@ -21,6 +21,24 @@ pub trait CoordinateFrame {
     where
         Self::Type: Copy + SaturatingNeg<Output = Self::Type>;
 
+    /// Consumes self and converts it to a [`NorthEastDown`] instance.
+    ///
+    /// Unlike [`to_ned`](Self::to_ned), this moves the components instead of
+    /// copying them and therefore does not require `Copy`.
+    fn into_ned(self) -> NorthEastDown<Self::Type>
+    where
+        Self: Sized,
+        Self::Type: SaturatingNeg<Output = Self::Type>;
+
+    /// Consumes self and converts it to an [`EastNorthUp`] instance.
+    ///
+    /// Unlike [`to_enu`](Self::to_enu), this moves the components instead of
+    /// copying them and therefore does not require `Copy`.
+    fn into_enu(self) -> EastNorthUp<Self::Type>
+    where
+        Self: Sized,
+        Self::Type: SaturatingNeg<Output = Self::Type>;
+
     /// Gets the value of the first dimension.
     fn x(&self) -> Self::Type
     where
@@ -73,6 +91,16 @@ pub trait CoordinateFrame {
         Self::Type: ZeroOne<Output = Self::Type> + core::ops::Neg<Output = Self::Type>;
 }
 
+/// Exposes the axis permutation of a coordinate frame at the type level.
+pub trait AxisPermutation {
+    /// Describes how the [`NorthEastDown`] components are obtained from this frame.
+    ///
+    /// For each of the _north_, _east_ and _down_ components, the first array holds the
+    /// index of the originating component in this frame, and the second array holds the
+    /// sign to apply to it.
+    const TO_NED: ([usize; 3], [i8; 3]);
+}
+
 /// Marks a right-handed coordinate system.
 pub trait RightHanded {}
 
@@ -97,6 +125,30 @@ pub trait SaturatingNeg {
     /// Like [`Neg`](core::ops::Neg), but returns the maximum value on negation of
     /// a minimum value instead of overflowing.
     fn saturating_neg(self) -> Self::Output;
+
+    /// Converts a [`NorthEastDown`] coordinate of this type to [`EastNorthUp`].
+    ///
+    /// This backs [`NorthEastDown::to_enu`] and can be overridden by types providing a
+    /// specialized conversion, such as `f32` with [`ned_to_enu`](crate::ned_to_enu).
+    fn ned_to_enu(ned: NorthEastDown<Self>) -> EastNorthUp<Self>
+    where
+        Self: Sized + SaturatingNeg<Output = Self>,
+    {
+        let [north, east, down] = ned.0;
+        EastNorthUp([east, north, down.saturating_neg()])
+    }
+
+    /// Converts an [`EastNorthUp`] coordinate of this type to [`NorthEastDown`].
+    ///
+    /// This backs [`EastNorthUp::to_ned`] and can be overridden by types providing a
+    /// specialized conversion, such as `f32` with [`enu_to_ned`](crate::enu_to_ned).
+    fn enu_to_ned(enu: EastNorthUp<Self>) -> NorthEastDown<Self>
+    where
+        Self: Sized + SaturatingNeg<Output = Self>,
+    {
+        let [east, north, up] = enu.0;
+        NorthEastDown([north, east, up.saturating_neg()])
+    }
 }
 
 impl SaturatingNeg for i8 {
@@ -144,6 +196,16 @@ impl SaturatingNeg for f32 {
     fn saturating_neg(self) -> Self {
         -self
     }
+
+    #[inline]
+    fn ned_to_enu(ned: NorthEastDown<Self>) -> EastNorthUp<Self> {
+        crate::ned_to_enu(ned)
+    }
+
+    #[inline]
+    fn enu_to_ned(enu: EastNorthUp<Self>) -> NorthEastDown<Self> {
+        crate::enu_to_ned(enu)
+    }
 }
 
 impl SaturatingNeg for f64 {
@@ -154,6 +216,502 @@ impl SaturatingNeg for f64 {
     }
 }
 
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl SaturatingNeg for half::f16 {
+    type Output = Self;
+
+    fn saturating_neg(self) -> Self {
+        -self
+    }
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl SaturatingNeg for half::bf16 {
+    type Output = Self;
+
+    fn saturating_neg(self) -> Self {
+        -self
+    }
+}
+
+/// Performs a checked negation.
+pub trait CheckedNeg {
+    type Output;
+
+    /// Like [`Neg`](core::ops::Neg), but returns `None` on negation of a minimum value
+    /// instead of overflowing.
+    fn checked_neg(self) -> Option<Self::Output>;
+}
+
+macro_rules! impl_checked_neg {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedNeg for $t {
+                type Output = Self;
+
+                fn checked_neg(self) -> Option<Self> {
+                    self.checked_neg()
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_neg!(i8, i16, i32, i64, i128, isize);
+
+impl CheckedNeg for f32 {
+    type Output = Self;
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl CheckedNeg for f64 {
+    type Output = Self;
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+/// Performs checked arithmetic, returning `None` on overflow.
+pub trait CheckedArithmetic {
+    type Output;
+
+    /// Like [`Add`](core::ops::Add), but returns `None` instead of overflowing.
+    fn checked_add(self, rhs: Self) -> Option<Self::Output>;
+
+    /// Like [`Sub`](core::ops::Sub), but returns `None` instead of overflowing.
+    fn checked_sub(self, rhs: Self) -> Option<Self::Output>;
+
+    /// Like [`Mul`](core::ops::Mul), but returns `None` instead of overflowing.
+    fn checked_mul(self, rhs: Self) -> Option<Self::Output>;
+}
+
+macro_rules! impl_checked_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArithmetic for $t {
+                type Output = Self;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.checked_add(rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    self.checked_sub(rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    self.checked_mul(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arithmetic!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Performs saturating arithmetic, clamping to the numeric bounds instead of overflowing.
+pub trait SaturatingArithmetic {
+    type Output;
+
+    /// Like [`Add`](core::ops::Add), but saturates at the numeric bounds instead of overflowing.
+    fn saturating_add(self, rhs: Self) -> Self::Output;
+
+    /// Like [`Sub`](core::ops::Sub), but saturates at the numeric bounds instead of overflowing.
+    fn saturating_sub(self, rhs: Self) -> Self::Output;
+
+    /// Like [`Mul`](core::ops::Mul), but saturates at the numeric bounds instead of overflowing.
+    fn saturating_mul(self, rhs: Self) -> Self::Output;
+}
+
+macro_rules! impl_saturating_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl SaturatingArithmetic for $t {
+                type Output = Self;
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    self.saturating_add(rhs)
+                }
+
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    self.saturating_sub(rhs)
+                }
+
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    self.saturating_mul(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_arithmetic!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Provides the absolute value.
+pub trait Abs {
+    type Output;
+
+    /// Returns the absolute value.
+    ///
+    /// For signed integers, the minimum value saturates to the maximum value.
+    fn abs(self) -> Self::Output;
+}
+
+macro_rules! impl_abs_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Abs for $t {
+                type Output = Self;
+
+                fn abs(self) -> Self {
+                    self.saturating_abs()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_abs_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Abs for $t {
+                type Output = Self;
+
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_signed!(i8, i16, i32, i64, i128, isize);
+impl_abs_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl Abs for f32 {
+    type Output = Self;
+
+    fn abs(self) -> Self {
+        // Clearing the sign bit is available without std.
+        f32::from_bits(self.to_bits() & !(1 << 31))
+    }
+}
+
+impl Abs for f64 {
+    type Output = Self;
+
+    fn abs(self) -> Self {
+        // Clearing the sign bit is available without std.
+        f64::from_bits(self.to_bits() & !(1 << 63))
+    }
+}
+
+/// Validates a component value, e.g. for use in [`debug_assert!`].
+///
+/// The default implementation accepts every value.
+pub trait Validate {
+    /// Returns an error describing why the value is invalid, or `Ok(())` if it is valid.
+    fn validate(&self) -> Result<(), &'static str> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_validate {
+    ($($t:ty),*) => {
+        $(
+            impl Validate for $t {}
+        )*
+    };
+}
+
+impl_validate!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+impl Validate for f32 {
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.is_finite() {
+            Ok(())
+        } else {
+            Err("component is not finite")
+        }
+    }
+}
+
+impl Validate for f64 {
+    fn validate(&self) -> Result<(), &'static str> {
+        if self.is_finite() {
+            Ok(())
+        } else {
+            Err("component is not finite")
+        }
+    }
+}
+
+/// Provides the integer square root.
+pub trait IntegerSqrt {
+    type Output;
+
+    /// Returns the square root of the value, rounded down. Negative values yield zero.
+    fn isqrt(self) -> Self::Output;
+}
+
+macro_rules! impl_integer_sqrt {
+    ($($t:ty),*) => {
+        $(
+            impl IntegerSqrt for $t {
+                type Output = Self;
+
+                fn isqrt(self) -> Self {
+                    if self <= 1 {
+                        return self.max(0);
+                    }
+
+                    // Newton's method; the iterates decrease monotonically toward the root.
+                    let mut x = self / 2;
+                    let mut y = (x + self / x) / 2;
+                    while y < x {
+                        x = y;
+                        y = (x + self / x) / 2;
+                    }
+                    x
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_sqrt!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Provides the square root.
+pub trait Sqrt {
+    type Output;
+
+    /// Returns the square root of the value.
+    fn sqrt(self) -> Self::Output;
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Sqrt for f32 {
+    type Output = Self;
+
+    fn sqrt(self) -> Self::Output {
+        self.sqrt()
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Sqrt for f64 {
+    type Output = Self;
+
+    fn sqrt(self) -> Self::Output {
+        self.sqrt()
+    }
+}
+
+#[cfg(all(
+    feature = "micromath",
+    not(feature = "std"),
+    not(feature = "num-traits")
+))]
+impl Sqrt for f32 {
+    type Output = Self;
+
+    fn sqrt(self) -> Self::Output {
+        micromath::F32Ext::sqrt(self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Sqrt for T
+where
+    T: num_traits::Float,
+{
+    type Output = T;
+
+    fn sqrt(self) -> Self::Output {
+        <T as num_traits::Float>::sqrt(self)
+    }
+}
+
+/// Provides the Euclidean distance of two values.
+pub trait Hypot {
+    type Output;
+
+    /// Calculates the length of the hypotenuse of a right-angled triangle with legs `self`
+    /// and `other`, i.e. `sqrt(self² + other²)`.
+    fn hypot(self, other: Self) -> Self::Output;
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Hypot for f32 {
+    type Output = Self;
+
+    fn hypot(self, other: Self) -> Self::Output {
+        self.hypot(other)
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Hypot for f64 {
+    type Output = Self;
+
+    fn hypot(self, other: Self) -> Self::Output {
+        self.hypot(other)
+    }
+}
+
+#[cfg(all(
+    feature = "micromath",
+    not(feature = "std"),
+    not(feature = "num-traits")
+))]
+impl Hypot for f32 {
+    type Output = Self;
+
+    fn hypot(self, other: Self) -> Self::Output {
+        micromath::F32Ext::hypot(self, other)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Hypot for T
+where
+    T: num_traits::Float,
+{
+    type Output = T;
+
+    fn hypot(self, other: Self) -> Self::Output {
+        <T as num_traits::Float>::hypot(self, other)
+    }
+}
+
+/// Provides the cube root.
+pub trait Cbrt {
+    type Output;
+
+    /// Returns the cube root of the value.
+    fn cbrt(self) -> Self::Output;
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Cbrt for f32 {
+    type Output = Self;
+
+    fn cbrt(self) -> Self::Output {
+        self.cbrt()
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Cbrt for f64 {
+    type Output = Self;
+
+    fn cbrt(self) -> Self::Output {
+        self.cbrt()
+    }
+}
+
+#[cfg(all(
+    feature = "micromath",
+    not(feature = "std"),
+    not(feature = "num-traits")
+))]
+impl Cbrt for f32 {
+    type Output = Self;
+
+    fn cbrt(self) -> Self::Output {
+        let root = micromath::F32Ext::powf(micromath::F32Ext::abs(self), 1.0 / 3.0);
+        micromath::F32Ext::copysign(root, self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Cbrt for T
+where
+    T: num_traits::Float,
+{
+    type Output = T;
+
+    fn cbrt(self) -> Self::Output {
+        <T as num_traits::Float>::cbrt(self)
+    }
+}
+
+/// Provides trigonometric functions.
+pub trait Trigonometry {
+    type Output;
+
+    /// Provides the value π.
+    fn pi() -> Self::Output;
+
+    /// Computes the four-quadrant arctangent of `self` (`y`) and `x` in radians.
+    fn atan2(self, x: Self) -> Self::Output;
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Trigonometry for f32 {
+    type Output = Self;
+
+    fn pi() -> Self::Output {
+        core::f32::consts::PI
+    }
+
+    fn atan2(self, x: Self) -> Self::Output {
+        self.atan2(x)
+    }
+}
+
+#[cfg(all(feature = "std", not(feature = "num-traits")))]
+impl Trigonometry for f64 {
+    type Output = Self;
+
+    fn pi() -> Self::Output {
+        core::f64::consts::PI
+    }
+
+    fn atan2(self, x: Self) -> Self::Output {
+        self.atan2(x)
+    }
+}
+
+#[cfg(all(
+    feature = "micromath",
+    not(feature = "std"),
+    not(feature = "num-traits")
+))]
+impl Trigonometry for f32 {
+    type Output = Self;
+
+    fn pi() -> Self::Output {
+        core::f32::consts::PI
+    }
+
+    fn atan2(self, x: Self) -> Self::Output {
+        micromath::F32Ext::atan2(self, x)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl<T> Trigonometry for T
+where
+    T: num_traits::Float + num_traits::FloatConst,
+{
+    type Output = T;
+
+    fn pi() -> Self::Output {
+        <T as num_traits::FloatConst>::PI()
+    }
+
+    fn atan2(self, x: Self) -> Self::Output {
+        <T as num_traits::Float>::atan2(self, x)
+    }
+}
+
 #[cfg(not(feature = "num-traits"))]
 impl ZeroOne for u8 {
     type Output = Self;
@@ -310,6 +868,88 @@ impl ZeroOne for f64 {
     }
 }
 
+#[cfg(all(feature = "half", not(feature = "num-traits")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl ZeroOne for half::f16 {
+    type Output = Self;
+
+    fn zero() -> Self::Output {
+        Self::ZERO
+    }
+
+    fn one() -> Self::Output {
+        Self::ONE
+    }
+}
+
+#[cfg(all(feature = "half", not(feature = "num-traits")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl ZeroOne for half::bf16 {
+    type Output = Self;
+
+    fn zero() -> Self::Output {
+        Self::ZERO
+    }
+
+    fn one() -> Self::Output {
+        Self::ONE
+    }
+}
+
+/// Implements [`ZeroOne`] and [`SaturatingNeg`] for the signed fixed-point types.
+///
+/// [`ZeroOne`] is only implemented for types with enough integer bits to represent one,
+/// i.e. not for e.g. `I0F32` or `I1F31`. With the `num-traits` feature, [`ZeroOne`] is instead
+/// provided through the `num-traits` implementations of the `fixed` crate.
+#[cfg(feature = "fixed")]
+macro_rules! impl_fixed {
+    ($($fixed:ident: $frac:ident, $one_frac:ident),*) => {
+        $(
+            #[cfg(not(feature = "num-traits"))]
+            #[cfg_attr(docsrs, doc(cfg(feature = "fixed")))]
+            impl<Frac> ZeroOne for fixed::$fixed<Frac>
+            where
+                Frac: fixed::types::extra::$frac
+                    + fixed::types::extra::IsLessOrEqual<
+                        fixed::types::extra::$one_frac,
+                        Output = fixed::types::extra::True,
+                    >,
+            {
+                type Output = Self;
+
+                fn zero() -> Self::Output {
+                    Self::ZERO
+                }
+
+                fn one() -> Self::Output {
+                    Self::ONE
+                }
+            }
+
+            #[cfg_attr(docsrs, doc(cfg(feature = "fixed")))]
+            impl<Frac> SaturatingNeg for fixed::$fixed<Frac>
+            where
+                Frac: fixed::types::extra::$frac,
+            {
+                type Output = Self;
+
+                fn saturating_neg(self) -> Self {
+                    self.saturating_neg()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "fixed")]
+impl_fixed!(
+    FixedI8: LeEqU8, U6,
+    FixedI16: LeEqU16, U14,
+    FixedI32: LeEqU32, U30,
+    FixedI64: LeEqU64, U62,
+    FixedI128: LeEqU128, U126
+);
+
 #[cfg(feature = "num-traits")]
 impl<T> ZeroOne for T
 where
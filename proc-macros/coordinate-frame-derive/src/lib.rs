@@ -2,31 +2,80 @@
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens};
-use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields, Ident, Lit};
+use syn::{parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Lit};
 
 const LATERAL: [&str; 2] = ["east", "west"];
 const LONGITUDINAL: [&str; 2] = ["north", "south"];
 const VERTICAL: [&str; 2] = ["down", "up"];
 const MUTUALLY_EXCLUSIVE: [[&str; 2]; 3] = [LATERAL, LONGITUDINAL, VERTICAL];
+const PRIMITIVES: [&str; 14] = [
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize", "f32",
+    "f64",
+];
 
-#[proc_macro_derive(CoordinateFrame)]
+#[proc_macro_derive(CoordinateFrame, attributes(coordinate_frame))]
 pub fn derive_coordinate_frame(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
 
+    let options = match Options::from_attributes(&input.attrs) {
+        Ok(options) => options,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+
     if let Data::Enum(data_enum) = input.data {
-        process_enum(name, data_enum)
+        process_enum(name, data_enum, options)
     } else {
         error_only_enums()
     }
 }
 
+/// Options provided via the `#[coordinate_frame(...)]` attribute.
+#[derive(Default)]
+struct Options {
+    /// Requires the enum to contain all 48 physical coordinate frames.
+    require_complete: bool,
+}
+
+impl Options {
+    /// Parses the options from the `#[coordinate_frame(...)]` attributes of the type.
+    fn from_attributes(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("coordinate_frame"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("require_complete") {
+                    options.require_complete = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported coordinate_frame option"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}
+
 /// Processes an enum of which we assume it is unit, i.e. (all) variants have no embedded values.
 fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
     let mut parse_u8_arms = Vec::new();
     let mut defmt_arms = Vec::new();
-    let mut display_arms = Vec::new();
+    let mut name_arms = Vec::new();
+    let mut from_str_arms = Vec::new();
     let mut convert_arms = Vec::new();
+    let mut express_conversions = Vec::new();
+    let mut physical_variants = Vec::new();
+    let mut all_variants = Vec::new();
+    let mut to_ned_arms = Vec::new();
+    let mut ned_matrix_arms = Vec::new();
+    let mut directions_arms = Vec::new();
+    let mut handedness_arms = Vec::new();
+    let mut flipped_arms = Vec::new();
+    let mut handedness_of = Vec::new();
+    let mut vertical_axis_of = Vec::new();
+    let mut to_enu_arms = Vec::new();
 
     let impls = data_enum.variants.iter().map(|variant| {
         let variant_name = &variant.ident;
@@ -39,8 +88,12 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
         }).expect("Enum variants must have explicit u8 values");
 
         let variant_name_str = format!("{variant_name}");
-        display_arms.push(quote! {
-            #enum_name :: #variant_name  => f.write_str(#variant_name_str),
+        name_arms.push(quote! {
+            #enum_name :: #variant_name  => #variant_name_str,
+        });
+
+        from_str_arms.push(quote! {
+            #variant_name_str => Ok(#enum_name :: #variant_name),
         });
 
         defmt_arms.push(quote! {
@@ -50,6 +103,7 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
         parse_u8_arms.push(quote! {
             #variant_value => Ok(#enum_name :: #variant_name),
         });
+        all_variants.push(variant_name.clone());
 
         // Ignore the special "Other" variant.
         if variant_name == "Other" || variant_name == "Undefined" {
@@ -59,8 +113,31 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 #enum_name :: #variant_name  => #variant_name :: new(x, y, z).to_ned().into(),
             });
 
+            express_conversions.push(quote! {
+                (#enum_name :: #variant_name, |ned| #variant_name :: from(ned).into()),
+            });
+            physical_variants.push(variant_name.clone());
+
+            to_ned_arms.push(quote! {
+                #enum_name :: #variant_name => Ok(#variant_name :: from_array(data).to_ned()),
+            });
+
+            to_enu_arms.push(quote! {
+                #enum_name :: #variant_name => Ok(#variant_name :: from_array(data).to_enu()),
+            });
+
             let components = split_variant_name_into_components(&variant_name.to_string());
 
+            let directions = components.iter().map(|component| format_ident!("{}", capitalize(component)));
+            directions_arms.push(quote! {
+                #enum_name :: #variant_name => Some([#(Direction:: #directions),*]),
+            });
+
+            let right_handed = is_right_handed(&components[0], &components[1], &components[2]);
+            handedness_arms.push(quote! {
+                #enum_name :: #variant_name => Some(#right_handed),
+            });
+
             // Implementations for each component.
             let mut components_impl = Vec::new();
 
@@ -114,7 +191,7 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
 
             // Generate derived pairs.
             let mut opposing_direction = Vec::new();
-            for component  in components.iter() {
+            for (i, component) in components.iter().enumerate() {
                 let pair = MUTUALLY_EXCLUSIVE.iter().copied().find(|&pair| pair.contains(&component.as_str())).expect("Failed to identify component pair");
                 let other = pair.iter().copied().find(|&other| !other.eq(component.as_str())).expect("Failed to find component's opposite direction");
                 opposing_direction.push(other);
@@ -124,6 +201,8 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 let other_name = format_ident!("{other}");
                 let clone_other_name = format_ident!("{other}_clone");
                 let doc_str = format!("Returns the _{other}_ component of this coordinate. This component is not a native axis of the coordinate frame and is derived from the [`{component}`](Self::{component}) component at runtime.");
+                let with_other_name = format_ident!("with_{other}");
+                let with_doc_str = format!("Consumes self and returns a new instance with the _{other}_ component set to the provided value. This component is not a native axis of the coordinate frame; the value is negated and stored in the [`{component}`](Self::{component}) component.");
 
                 components_impl.push(quote! {
                     #[doc = #doc_str]
@@ -138,15 +217,32 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         let component = self . #clone_component_name();
                         component.saturating_neg()
                     }
+
+                    #[doc = #with_doc_str]
+                    #[inline]
+                    pub fn #with_other_name (mut self, #other_name: T) -> Self where T: SaturatingNeg<Output = T> {
+                        self.0[#i] = #other_name.saturating_neg();
+                        self
+                    }
                 });
             }
 
             // Create flipped version.
             let flipped_name = String::from_iter(opposing_direction.iter().map(|component| capitalize(component)));
             let flipped_ident = format_ident!("{}", flipped_name);
-            let flip_doc = format!("Flips this coordinate frame into its opposite frame, [`{flipped_name}`]");
+            let flip_doc = format!("Flips this coordinate frame into its opposite frame, [`{flipped_name}`].");
+            let saturating_flip_doc = format!("Flips this coordinate frame into its opposite frame, [`{flipped_name}`], saturating on overflow.");
+            let checked_flip_doc = format!("Flips this coordinate frame into its opposite frame, [`{flipped_name}`], or returns `None` on overflow.");
+            flipped_arms.push(quote! {
+                #enum_name :: #variant_name => #enum_name :: #flipped_ident,
+            });
             components_impl.push(quote! {
                 #[doc = #flip_doc]
+                ///
+                /// Flipping negates every component. For signed integers, negating the minimum
+                /// value saturates to the maximum value, e.g. `i8::MIN` becomes `i8::MAX`; this is
+                /// the same as [`saturating_flip_frame`](Self::saturating_flip_frame). Use
+                /// [`checked_flip_frame`](Self::checked_flip_frame) to detect this case.
                 #[inline]
                 pub fn flip_frame(&self) -> #flipped_ident <T>
                 where
@@ -154,8 +250,147 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 {
                     (*self).into()
                 }
+
+                #[doc = #saturating_flip_doc]
+                ///
+                /// For signed integers, negating the minimum value saturates to the maximum value.
+                #[inline]
+                pub fn saturating_flip_frame(&self) -> #flipped_ident <T>
+                where
+                    T: Copy + SaturatingNeg<Output = T>
+                {
+                    self.flip_frame()
+                }
+
+                #[doc = #checked_flip_doc]
+                #[inline]
+                pub fn checked_flip_frame(&self) -> Option<#flipped_ident <T>>
+                where
+                    T: Copy + CheckedNeg<Output = T>
+                {
+                    let [x, y, z] = self.0;
+                    Some(#flipped_ident ([x.checked_neg()?, y.checked_neg()?, z.checked_neg()?]))
+                }
+            });
+
+            // Create mirrored versions, each flipping exactly one axis.
+            for (i, component) in components.iter().enumerate() {
+                let direction = axis_direction(component);
+                let mirrored_name = String::from_iter(components.iter().enumerate().map(|(j, component)| {
+                    if i == j { capitalize(opposing_direction[j]) } else { capitalize(component) }
+                }));
+                let mirrored_ident = format_ident!("{}", mirrored_name);
+                let mirror_function_name = format_ident!("mirror_{direction}");
+                let mirror_doc = format!("Mirrors this coordinate frame along its {direction} axis into the opposite-handed frame, [`{mirrored_name}`].");
+                components_impl.push(quote! {
+                    #[doc = #mirror_doc]
+                    #[inline]
+                    pub fn #mirror_function_name(&self) -> #mirrored_ident <T>
+                    where
+                        T: Copy + SaturatingNeg<Output = T>
+                    {
+                        (*self).into()
+                    }
+                });
+            }
+
+            // Access by physical direction.
+            let mut component_arms = Vec::new();
+            let mut index_arms = Vec::new();
+            for (i, component) in components.iter().enumerate() {
+                let direction = format_ident!("{}", capitalize(component));
+                let opposing = format_ident!("{}", capitalize(opposing_direction[i]));
+                let component_name = format_ident!("{component}");
+                let opposing_name = format_ident!("{}", opposing_direction[i]);
+                component_arms.push(quote! {
+                    Direction:: #direction => self. #component_name (),
+                    Direction:: #opposing => self. #opposing_name (),
+                });
+                index_arms.push(quote! {
+                    Direction:: #direction => &self.0[#i],
+                });
+            }
+            let index_panic = format!("The {{direction:?}} direction is not a native axis of {variant_name}; use the component function instead");
+            components_impl.push(quote! {
+                /// Returns the component pointing in the specified direction.
+                ///
+                /// Native directions are returned as-is, whereas their opposite directions are
+                /// derived from the native component at runtime.
+                pub fn component(&self, direction: Direction) -> T
+                where
+                    T: Copy + SaturatingNeg<Output = T>
+                {
+                    match direction {
+                        #(#component_arms)*
+                    }
+                }
+
+                /// Returns the component pointing in the specified direction.
+                ///
+                /// Every direction is representable in a statically typed frame, so this
+                /// always returns `Some`; see [`DynamicFrame::get`] for the fallible
+                /// counterpart.
+                pub fn get(&self, direction: Direction) -> Option<T>
+                where
+                    T: Copy + SaturatingNeg<Output = T>
+                {
+                    Some(self.component(direction))
+                }
             });
 
+            let index_impl = quote! {
+                impl<T, I> core::ops::Index<I> for #variant_name <T>
+                where
+                    I: core::slice::SliceIndex<[T]>
+                {
+                    type Output = I::Output;
+
+                    fn index(&self, index: I) -> &Self::Output {
+                        &self.0[index]
+                    }
+                }
+
+                impl<T, I> core::ops::IndexMut<I> for #variant_name <T>
+                where
+                    I: core::slice::SliceIndex<[T]>
+                {
+                    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+                        &mut self.0[index]
+                    }
+                }
+
+                /// Indexes the components by their [`Axis`].
+                impl<T> core::ops::Index<Axis> for #variant_name <T> {
+                    type Output = T;
+
+                    fn index(&self, axis: Axis) -> &T {
+                        &self.0[axis.index()]
+                    }
+                }
+
+                impl<T> core::ops::IndexMut<Axis> for #variant_name <T> {
+                    fn index_mut(&mut self, axis: Axis) -> &mut T {
+                        &mut self.0[axis.index()]
+                    }
+                }
+
+                /// Indexes the native components by their [`Direction`].
+                ///
+                /// ## Panics
+                /// Only native directions can be returned by reference; indexing a derived
+                /// direction panics. Use the `component` function for these instead.
+                impl<T> core::ops::Index<Direction> for #variant_name <T> {
+                    type Output = T;
+
+                    fn index(&self, direction: Direction) -> &T {
+                        match direction {
+                            #(#index_arms)*
+                            _ => panic!(#index_panic),
+                        }
+                    }
+                }
+            };
+
             // Create constructor.
             let first_component = format_ident!("{}", &components[0]);
             let second_component = format_ident!("{}", &components[1]);
@@ -164,11 +399,107 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 &components[0], &components[1], &components[2]
             );
 
-            // Provide conversion to North, East, Down
-            let north = String::from("north");
-            let east = String::from("east");
-            let down = String::from("down");
-            if variant_name != "NorthEastDown" && components.contains(&north) && components.contains(&east) && components.contains(&down) {
+            // Permutations to North, East, Down and East, North, Up
+            let ned_permutation = permutation(&components, ["north", "east", "down"]);
+            let enu_permutation = permutation(&components, ["east", "north", "up"]);
+            let (ned_indices, ned_signs): (Vec<_>, Vec<_>) = ned_permutation.iter().copied().unzip();
+
+            // The signed permutation matrix mapping this frame to North, East, Down.
+            let ned_matrix_rows = ned_permutation.iter().map(|&(index, sign)| {
+                let row = [0, 1, 2].map(|column| if column == index { sign } else { 0 });
+                quote! { [#(#row),*] }
+            });
+            ned_matrix_arms.push(quote! {
+                #enum_name :: #variant_name => Some([#(#ned_matrix_rows),*]),
+            });
+
+            // The same matrix, expressed in the component type.
+            let ned_matrix_rows_t = ned_permutation.iter().map(|&(index, sign)| {
+                let row = [0, 1, 2].map(|column| match (column == index, sign > 0) {
+                    (false, _) => quote! { T::zero() },
+                    (true, true) => quote! { T::one() },
+                    (true, false) => quote! { -T::one() },
+                });
+                quote! { [#(#row),*] }
+            });
+            components_impl.push(quote! {
+                /// Converts this type to a [`NorthEastDown`] instance and additionally returns
+                /// the signed permutation matrix that was applied.
+                ///
+                /// The matrix is row-major and maps this frame's `[x, y, z]` components to
+                /// north, east and down; it can be reused to transform related quantities
+                /// such as covariances expressed in this frame.
+                pub fn to_ned_with_matrix(&self) -> (NorthEastDown<T>, [[T; 3]; 3])
+                where
+                    T: Copy + SaturatingNeg<Output = T> + ZeroOne<Output = T> + core::ops::Neg<Output = T>
+                {
+                    (self.to_ned(), [#(#ned_matrix_rows_t),*])
+                }
+            });
+
+            // Const conversions to every other frame. Pure permutations work for any `Copy`
+            // type; conversions that negate components are only provided for the signed
+            // integer types, whose `saturating_neg` is usable in const contexts.
+            let mut const_conversions = Vec::new();
+            let mut const_negating_conversions = Vec::new();
+            for target in all_permutations() {
+                if *variant_name == target {
+                    continue;
+                }
+
+                let target_components = split_variant_name_into_components(&target);
+                let target_permutation = permutation(&components, [
+                    target_components[0].as_str(),
+                    target_components[1].as_str(),
+                    target_components[2].as_str(),
+                ]);
+                let target_ident = format_ident!("{target}");
+                let function_name = format_ident!("to_{}", target_components.join("_"));
+                let doc = format!("Converts this coordinate to [`{target}`] in a `const` context.");
+
+                if target_permutation.iter().all(|&(_, sign)| sign > 0) {
+                    let indices = target_permutation.iter().map(|&(index, _)| index);
+                    const_conversions.push(quote! {
+                        #[doc = #doc]
+                        pub const fn #function_name (&self) -> #target_ident <T> where T: Copy {
+                            #target_ident ([#(self.0[#indices]),*])
+                        }
+                    });
+                } else {
+                    let values = target_permutation.iter().map(|&(index, sign)| {
+                        if sign > 0 {
+                            quote! { self.0[#index] }
+                        } else {
+                            quote! { self.0[#index].saturating_neg() }
+                        }
+                    });
+                    const_negating_conversions.push((doc, function_name, target_ident, values.collect::<Vec<_>>()));
+                }
+            }
+
+            let const_integer_conversions = ["i8", "i16", "i32", "i64", "i128", "isize"].map(|ty| {
+                let ty = format_ident!("{ty}");
+                let functions = const_negating_conversions.iter().map(|(doc, function_name, target_ident, values)| {
+                    quote! {
+                        #[doc = #doc]
+                        ///
+                        /// Negated components saturate, i.e. the minimum value becomes the maximum value.
+                        pub const fn #function_name (&self) -> #target_ident <#ty> {
+                            #target_ident ([#(#values),*])
+                        }
+                    }
+                });
+                quote! {
+                    impl #variant_name <#ty> {
+                        #(#functions)*
+                    }
+                }
+            });
+            components_impl.extend(const_conversions);
+
+            // Provide conversion to North, East, Down; if the target axes are all native
+            // to this frame, the conversion is a pure permutation and needs no negation.
+            if ned_permutation.iter().all(|&(_, sign)| sign > 0) {
                 components_impl.push(quote! {
                     /// Converts this type to a [`NorthEastDown`] instance.
                     pub const fn to_ned(&self) -> NorthEastDown<T> where T: Copy {
@@ -178,6 +509,14 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         NorthEastDown::new(north, east, down)
                     }
                 });
+            } else if variant_name == "EastNorthUp" {
+                // Delegate to the element type so that specialized conversions can be used.
+                components_impl.push(quote! {
+                    /// Converts this type to a [`NorthEastDown`] instance.
+                    pub fn to_ned(&self) -> NorthEastDown<T> where T: Copy + SaturatingNeg<Output = T> {
+                        T::enu_to_ned(*self)
+                    }
+                });
             } else {
                 components_impl.push(quote! {
                     /// Converts this type to a [`NorthEastDown`] instance.
@@ -191,8 +530,7 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
             }
 
             // Provide conversion to East, North, Up
-            let up = String::from("up");
-            if variant_name != "EastNorthUp" && components.contains(&east) && components.contains(&north) && components.contains(&up) {
+            if enu_permutation.iter().all(|&(_, sign)| sign > 0) {
                 components_impl.push(quote! {
                     /// Converts this type to an [`NorthEastDown`] instance.
                     pub const fn to_enu(&self) -> EastNorthUp<T> where T: Copy {
@@ -202,6 +540,14 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         EastNorthUp::new(east, north, up)
                     }
                 });
+            } else if variant_name == "NorthEastDown" {
+                // Delegate to the element type so that specialized conversions can be used.
+                components_impl.push(quote! {
+                    /// Converts this type to an [`EastNorthUp`] instance.
+                    pub fn to_enu(&self) -> EastNorthUp<T> where T: Copy + SaturatingNeg<Output = T> {
+                        T::ned_to_enu(*self)
+                    }
+                });
             } else {
                 components_impl.push(quote! {
                     /// Converts this type to an [`EastNorthUp`] instance.
@@ -244,9 +590,130 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 });
             }
 
+            // Consuming conversions
+            let component_values = [format_ident!("x"), format_ident!("y"), format_ident!("z")];
+            let permuted_values = |permutation: &[(usize, i8); 3]| permutation.map(|(i, sign)| {
+                let value = &component_values[i];
+                if sign < 0 {
+                    quote! { #value.saturating_neg() }
+                } else {
+                    quote! { #value }
+                }
+            });
+            let [north_value, east_value, down_value] = permuted_values(&ned_permutation);
+            let [east_value_enu, north_value_enu, up_value] = permuted_values(&enu_permutation);
+            let ned_neg_bound = if ned_permutation.iter().any(|&(_, sign)| sign < 0) {
+                quote! { where T: SaturatingNeg<Output = T> }
+            } else {
+                quote! {}
+            };
+            let enu_neg_bound = if enu_permutation.iter().any(|&(_, sign)| sign < 0) {
+                quote! { where T: SaturatingNeg<Output = T> }
+            } else {
+                quote! {}
+            };
+            components_impl.push(quote! {
+                /// Consumes self and converts it to a [`NorthEastDown`] instance.
+                ///
+                /// Unlike [`to_ned`](Self::to_ned), this moves the components instead of
+                /// copying them and therefore does not require `T: Copy`.
+                pub fn into_ned(self) -> NorthEastDown<T> #ned_neg_bound {
+                    let [x, y, z] = self.0;
+                    NorthEastDown::new(#north_value, #east_value, #down_value)
+                }
+
+                /// Consumes self and converts it to an [`EastNorthUp`] instance.
+                ///
+                /// Unlike [`to_enu`](Self::to_enu), this moves the components instead of
+                /// copying them and therefore does not require `T: Copy`.
+                pub fn into_enu(self) -> EastNorthUp<T> #enu_neg_bound {
+                    let [x, y, z] = self.0;
+                    EastNorthUp::new(#east_value_enu, #north_value_enu, #up_value)
+                }
+            });
+
+            // Borrowing conversions; the identity case avoids cloning.
+            let ned_cow = if *variant_name == "NorthEastDown" {
+                quote! { alloc::borrow::Cow::Borrowed(self) }
+            } else {
+                quote! { alloc::borrow::Cow::Owned(self.clone().into_ned()) }
+            };
+            let enu_cow = if *variant_name == "EastNorthUp" {
+                quote! { alloc::borrow::Cow::Borrowed(self) }
+            } else {
+                quote! { alloc::borrow::Cow::Owned(self.clone().into_enu()) }
+            };
+            components_impl.push(quote! {
+                /// Converts this coordinate to [`NorthEastDown`], borrowing instead of cloning
+                /// if it already is in that frame.
+                ///
+                /// Unlike [`to_ned`](Self::to_ned), this only requires `T: Clone`.
+                #[cfg(feature = "alloc")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+                pub fn as_ned_cow(&self) -> alloc::borrow::Cow<'_, NorthEastDown<T>>
+                where
+                    T: Clone + SaturatingNeg<Output = T>
+                {
+                    #ned_cow
+                }
+
+                /// Converts this coordinate to [`EastNorthUp`], borrowing instead of cloning
+                /// if it already is in that frame.
+                ///
+                /// Unlike [`to_enu`](Self::to_enu), this only requires `T: Clone`.
+                #[cfg(feature = "alloc")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+                pub fn as_enu_cow(&self) -> alloc::borrow::Cow<'_, EastNorthUp<T>>
+                where
+                    T: Clone + SaturatingNeg<Output = T>
+                {
+                    #enu_cow
+                }
+            });
+
+            // Conversions from and to glam vector types.
+            let glam_impl = [("Vec3", "f32"), ("Vec3A", "f32"), ("IVec3", "i32"), ("DVec3", "f64")]
+                .map(|(vector, primitive)| {
+                    let vector = format_ident!("{vector}");
+                    let primitive = format_ident!("{primitive}");
+                    quote! {
+                        #[cfg(feature = "glam")]
+                        #[cfg_attr(docsrs, doc(cfg(feature = "glam")))]
+                        impl From<glam:: #vector> for #variant_name <#primitive> {
+                            fn from(value: glam:: #vector) -> #variant_name <#primitive> {
+                                Self(value.to_array())
+                            }
+                        }
+
+                        #[cfg(feature = "glam")]
+                        #[cfg_attr(docsrs, doc(cfg(feature = "glam")))]
+                        impl From<#variant_name <#primitive>> for glam:: #vector {
+                            fn from(value: #variant_name <#primitive>) -> glam:: #vector {
+                                glam:: #vector ::from_array(value.0)
+                            }
+                        }
+                    }
+                });
+
+            // Scalar multiplication with the scalar on the left-hand side.
+            let left_mul_impl = PRIMITIVES.iter().map(|primitive| {
+                let primitive = format_ident!("{primitive}");
+                quote! {
+                    impl core::ops::Mul<#variant_name <#primitive>> for #primitive {
+                        type Output = #variant_name <#primitive>;
+
+                        fn mul(self, rhs: #variant_name <#primitive>) -> Self::Output {
+                            rhs * self
+                        }
+                    }
+                }
+            });
+
             // Handedness
             let right_handed = is_right_handed(&components[0], &components[1], &components[2]);
 
+            let cross_sign: i8 = if right_handed { 1 } else { -1 };
+
             let mut handedness_impl = Vec::new();
             if right_handed {
                 handedness_impl.push(quote!{
@@ -263,6 +730,21 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
             let x_axis_vec = axis_def_t(&components[0]);
             let y_axis_vec = axis_def_t(&components[1]);
             let z_axis_vec = axis_def_t(&components[2]);
+            let [x_axis_ned, y_axis_ned, z_axis_ned] =
+                [0, 1, 2].map(|i| ned_axis_t(&components[i]));
+            let horizontal_indices: Vec<_> = (0..3usize)
+                .filter(|&i| axis_direction(&components[i]) != "vertical")
+                .collect();
+            let vertical_index = (0..3usize)
+                .find(|&i| axis_direction(&components[i]) == "vertical")
+                .expect("Frame has no vertical axis");
+            let vertical_axis = format_ident!("{}", ["X", "Y", "Z"][vertical_index]);
+            let vertical_direction = format_ident!("{}", capitalize(&components[vertical_index]));
+
+            // Const metadata lookups by dense physical index.
+            let handedness = format_ident!("{}", if right_handed { "Right" } else { "Left" });
+            handedness_of.push(quote! { Handedness:: #handedness });
+            vertical_axis_of.push(quote! { Axis:: #vertical_axis });
 
             // Documentation for x, y and z.
             let x_doc = format!("For this type, this represents the [`{first_component}`](Self::{first_component}) direction.");
@@ -375,10 +857,104 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                #[cfg(feature = "approx")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+                impl<T> approx::AbsDiffEq for #variant_name <T>
+                where
+                    T: approx::AbsDiffEq,
+                    T::Epsilon: Clone
+                {
+                    type Epsilon = T::Epsilon;
+
+                    fn default_epsilon() -> Self::Epsilon {
+                        T::default_epsilon()
+                    }
+
+                    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                        self.0
+                            .iter()
+                            .zip(other.0.iter())
+                            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+                    }
+                }
+
+                #[cfg(feature = "approx")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+                impl<T> approx::RelativeEq for #variant_name <T>
+                where
+                    T: approx::RelativeEq,
+                    T::Epsilon: Clone
+                {
+                    fn default_max_relative() -> Self::Epsilon {
+                        T::default_max_relative()
+                    }
+
+                    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                        self.0
+                            .iter()
+                            .zip(other.0.iter())
+                            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+                    }
+                }
+
+                #[cfg(feature = "approx")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "approx")))]
+                impl<T> approx::UlpsEq for #variant_name <T>
+                where
+                    T: approx::UlpsEq,
+                    T::Epsilon: Clone
+                {
+                    fn default_max_ulps() -> u32 {
+                        T::default_max_ulps()
+                    }
+
+                    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                        self.0
+                            .iter()
+                            .zip(other.0.iter())
+                            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+                    }
+                }
+
+                #[cfg(feature = "serde")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+                impl<T> serde::Serialize for #variant_name <T> where T: serde::Serialize {
+                    /// Serializes the coordinate as a sequence of its three components.
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::Serializer
+                    {
+                        self.0.serialize(serializer)
+                    }
+                }
+
+                #[cfg(feature = "serde")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+                impl<'de, T> serde::Deserialize<'de> for #variant_name <T> where T: serde::Deserialize<'de> {
+                    /// Deserializes the coordinate from a sequence of its three components.
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: serde::Deserializer<'de>
+                    {
+                        <[T; 3]>::deserialize(deserializer).map(Self::from_array)
+                    }
+                }
+
                 impl<T> #variant_name <T> {
                     /// The coordinate frame type.
                     pub const COORDINATE_FRAME: #enum_name = #enum_name :: #variant_name;
 
+                    /// The sign convention of the [`cross`](Self::cross) product in this frame,
+                    /// i.e. `1` for right-handed and `-1` for left-handed frames.
+                    pub const CROSS_SIGN: i8 = #cross_sign;
+
+                    /// The axis representing the vertical direction in this frame.
+                    pub const VERTICAL_AXIS: Axis = Axis:: #vertical_axis;
+
+                    /// The native vertical direction of this frame, i.e. whether its
+                    /// [`VERTICAL_AXIS`](Self::VERTICAL_AXIS) points up or down.
+                    pub const VERTICAL_DIRECTION: Direction = Direction:: #vertical_direction;
+
                     #[doc = #new_doc]
                     pub const fn new(#first_component: T, #second_component: T, #third_component: T) -> Self {
                         Self([#first_component, #second_component, #third_component])
@@ -401,10 +977,30 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         Self(vec)
                     }
 
+                    /// Constructs an instance from the components of a [`NorthEastDown`] coordinate.
+                    pub fn from_ned_array(arr: [T; 3]) -> Self
+                    where
+                        Self: From<NorthEastDown<T>>
+                    {
+                        Self::from(NorthEastDown::from_array(arr))
+                    }
+
+                    /// Constructs an instance from the components of an [`EastNorthUp`] coordinate.
+                    pub fn from_enu_array(arr: [T; 3]) -> Self
+                    where
+                        Self: From<EastNorthUp<T>>
+                    {
+                        Self::from(EastNorthUp::from_array(arr))
+                    }
+
                     /// Constructs an instance from a slice.
                     ///
                     /// Be mindful not to directly pass a different coordinate frame into
                     /// this function unless you want to strictly re-interpret the values.
+                    ///
+                    /// ## Panics
+                    /// Panics if the slice does not have a length of exactly 3. Use the
+                    /// `TryFrom<&[T]>` implementation to handle this case gracefully.
                     pub fn from_slice(vec: &[T]) -> Self
                     where
                         T: Clone
@@ -416,34 +1012,136 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         Self([x, y, z])
                     }
 
-                    /// Gets the value of the first dimension.
-                    #[doc = #x_doc]
-                    pub fn x(&self) -> T where T: Clone {
-                        self.0[0].clone()
+                    /// Constructs an instance from the first three items of the iterator,
+                    /// ignoring any further items.
+                    ///
+                    /// ## Errors
+                    /// Returns [`ParseCoordinateFrameError::WrongLength`] if the iterator
+                    /// yields fewer than three items.
+                    pub fn try_from_iter<I>(iter: I) -> Result<Self, ParseCoordinateFrameError>
+                    where
+                        I: IntoIterator<Item = T>
+                    {
+                        let mut iter = iter.into_iter();
+                        let error = |got| ParseCoordinateFrameError::WrongLength { expected: 3, got };
+                        let x = iter.next().ok_or(error(0))?;
+                        let y = iter.next().ok_or(error(1))?;
+                        let z = iter.next().ok_or(error(2))?;
+                        Ok(Self([x, y, z]))
                     }
 
-                    /// Gets the value of the second dimension.
-                    #[doc = #y_doc]
-                    pub fn y(&self) -> T where T: Clone {
-                        self.0[1].clone()
+                    /// Constructs an instance from a row of a row-major matrix, i.e. `matrix[row]`.
+                    ///
+                    /// ## Panics
+                    /// Panics if `row` is out of bounds.
+                    pub fn from_row(matrix: &[[T; 3]; 3], row: usize) -> Self
+                    where
+                        T: Clone
+                    {
+                        Self(matrix[row].clone())
                     }
 
-                    /// Gets the value of the third dimension.
-                    #[doc = #z_doc]
-                    pub fn z(&self) -> T where T: Clone {
-                        self.0[2].clone()
+                    /// Constructs an instance from a column of a row-major matrix, i.e. `matrix[..][column]`.
+                    ///
+                    /// ## Panics
+                    /// Panics if `column` is out of bounds.
+                    pub fn from_column(matrix: &[[T; 3]; 3], column: usize) -> Self
+                    where
+                        T: Clone
+                    {
+                        let x = matrix[0][column].clone();
+                        let y = matrix[1][column].clone();
+                        let z = matrix[2][column].clone();
+                        Self([x, y, z])
                     }
 
-                    /// Gets a reference to the value of the first dimension.
-                    #[doc = #x_doc]
-                    pub fn x_ref(&self) -> &T {
-                        &self.0[0]
+                    /// Validates the components of this coordinate, e.g. after constructing it from
+                    /// untrusted data via [`from_array`](Self::from_array).
+                    ///
+                    /// This is intended as a hook for [`debug_assert!`]; for floating-point types
+                    /// it rejects non-finite components, whereas integer types are always valid.
+                    pub fn debug_validate(&self) -> Result<(), &'static str>
+                    where
+                        T: Validate
+                    {
+                        self.0.iter().try_for_each(Validate::validate)
                     }
 
-                    /// Gets a reference to the value of the second dimension.
-                    #[doc = #y_doc]
-                    pub fn y_ref(&self) -> &T {
-                        &self.0[1]
+                    /// Returns the components in the specified index range as a slice.
+                    ///
+                    /// ## Panics
+                    /// Panics if the range is out of bounds.
+                    pub fn slice(&self, range: core::ops::Range<usize>) -> &[T] {
+                        &self.0[range]
+                    }
+
+                    /// Converts this coordinate into homogeneous coordinates, i.e. `[x, y, z, 1]`.
+                    pub fn to_homogeneous(&self) -> [T; 4]
+                    where
+                        T: Clone + ZeroOne<Output = T>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        [x, y, z, T::one()]
+                    }
+
+                    /// Constructs an instance from homogeneous coordinates `[x, y, z, w]` by
+                    /// dividing each component by `w`.
+                    ///
+                    /// ## Division by zero
+                    /// If `w` is zero, the behavior is that of the element type's division:
+                    /// floating-point types produce infinite or NaN components, whereas integer
+                    /// types panic. Use [`from_homogeneous_checked`](Self::from_homogeneous_checked)
+                    /// to detect this case.
+                    pub fn from_homogeneous(vec: [T; 4]) -> Self
+                    where
+                        T: Clone + core::ops::Div<T, Output = T>
+                    {
+                        let [x, y, z, w] = vec;
+                        Self([x / w.clone(), y / w.clone(), z / w])
+                    }
+
+                    /// Constructs an instance from homogeneous coordinates `[x, y, z, w]` by
+                    /// dividing each component by `w`.
+                    ///
+                    /// Returns `None` if `w` is zero.
+                    pub fn from_homogeneous_checked(vec: [T; 4]) -> Option<Self>
+                    where
+                        T: Clone + PartialEq + ZeroOne<Output = T> + core::ops::Div<T, Output = T>
+                    {
+                        if vec[3] == T::zero() {
+                            return None;
+                        }
+                        Some(Self::from_homogeneous(vec))
+                    }
+
+                    /// Gets the value of the first dimension.
+                    #[doc = #x_doc]
+                    pub fn x(&self) -> T where T: Clone {
+                        self.0[0].clone()
+                    }
+
+                    /// Gets the value of the second dimension.
+                    #[doc = #y_doc]
+                    pub fn y(&self) -> T where T: Clone {
+                        self.0[1].clone()
+                    }
+
+                    /// Gets the value of the third dimension.
+                    #[doc = #z_doc]
+                    pub fn z(&self) -> T where T: Clone {
+                        self.0[2].clone()
+                    }
+
+                    /// Gets a reference to the value of the first dimension.
+                    #[doc = #x_doc]
+                    pub fn x_ref(&self) -> &T {
+                        &self.0[0]
+                    }
+
+                    /// Gets a reference to the value of the second dimension.
+                    #[doc = #y_doc]
+                    pub fn y_ref(&self) -> &T {
+                        &self.0[1]
                     }
 
                     /// Gets a reference to the value of the third dimension.
@@ -470,6 +1168,19 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         &mut self.0[2]
                     }
 
+                    /// Gets references to the values of all three dimensions at once.
+                    pub const fn components(&self) -> (&T, &T, &T) {
+                        let [x, y, z] = &self.0;
+                        (x, y, z)
+                    }
+
+                    /// Gets mutable references to the values of all three dimensions at once,
+                    /// e.g. for modifying them together.
+                    pub fn components_mut(&mut self) -> (&mut T, &mut T, &mut T) {
+                        let [x, y, z] = &mut self.0;
+                        (x, y, z)
+                    }
+
                     /// Consumes self and returns its inner value.
                     pub const fn into_inner(self) -> [T; 3] where T: Copy {
                         self.0
@@ -503,49 +1214,712 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         #z_axis_vec
                     }
 
+                    /// Returns the unit vector of the specified local axis expressed in
+                    /// [`NorthEastDown`], i.e. the NED direction the axis points toward.
+                    pub fn axis_in_ned(&self, axis: Axis) -> NorthEastDown<T>
+                    where
+                        T: ZeroOne<Output = T> + core::ops::Neg<Output = T>
+                    {
+                        let [north, east, down] = match axis {
+                            Axis::X => #x_axis_ned,
+                            Axis::Y => #y_axis_ned,
+                            Axis::Z => #z_axis_ned,
+                        };
+                        NorthEastDown::new(north, east, down)
+                    }
+
                     /// Calculates the squared norm of the components.
                     ///
                     /// ## Panics
                     /// This operation may overflow.
-                    pub fn norm_sq(&self) -> T where T: Clone + core::ops::Mul<T, Output = T> + core::ops::Add<T, Output = T> {
-                        let x = self.x().clone();
-                        let y = self.y().clone();
-                        let z = self.z().clone();
-                        x.clone() * x + y.clone() * y + z.clone() * z
+                    pub fn norm_sq(&self) -> T
+                    where
+                        T: core::ops::Add<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let [x, y, z] = &self.0;
+                        x * x + y * y + z * z
+                    }
+
+                    /// Calculates the norm (length) of the components, i.e. `norm_sq().sqrt()`.
+                    ///
+                    /// ## Overflow
+                    /// The squared norm is calculated first, so it may overflow for very large
+                    /// components even if the norm itself is representable; for floating-point
+                    /// types this yields infinity.
+                    pub fn norm(&self) -> T
+                    where
+                        T: Sqrt<Output = T>
+                            + core::ops::Add<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        self.norm_sq().sqrt()
+                    }
+
+                    /// Calculates the L1 (Manhattan) norm of the components, i.e. `|x| + |y| + |z|`.
+                    pub fn norm_l1(&self) -> T
+                    where
+                        T: Clone + Abs<Output = T> + core::ops::Add<T, Output = T>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        x.abs() + y.abs() + z.abs()
+                    }
+
+                    /// Calculates the L∞ (Chebyshev) norm of the components, i.e. the maximum
+                    /// absolute component.
+                    pub fn norm_linf(&self) -> T
+                    where
+                        T: Clone + PartialOrd + Abs<Output = T>
+                    {
+                        let [x, y, z] = self.0.clone().map(Abs::abs);
+                        let max = if y > x { y } else { x };
+                        if z > max { z } else { max }
+                    }
+
+                    /// Returns the component-wise absolute value.
+                    pub fn abs(&self) -> Self where T: Clone + Abs<Output = T> {
+                        Self(self.0.clone().map(Abs::abs))
+                    }
+
+                    /// Returns the component-wise minimum of this coordinate and `other`.
+                    ///
+                    /// This takes `self` by value so that it takes precedence over the lexicographic
+                    /// [`Ord::min`] in method resolution; a `&self` receiver would resolve to
+                    /// [`Ord::min`] for frames of `Ord` types. Clone the coordinate first to keep it.
+                    pub fn min(self, other: &Self) -> Self where T: Clone + PartialOrd {
+                        let [x, y, z] = self.0;
+                        let [ox, oy, oz] = other.0.clone();
+                        Self([
+                            if ox < x { ox } else { x },
+                            if oy < y { oy } else { y },
+                            if oz < z { oz } else { z },
+                        ])
+                    }
+
+                    /// Returns the component-wise maximum of this coordinate and `other`.
+                    ///
+                    /// This takes `self` by value so that it takes precedence over the lexicographic
+                    /// [`Ord::max`] in method resolution; a `&self` receiver would resolve to
+                    /// [`Ord::max`] for frames of `Ord` types. Clone the coordinate first to keep it.
+                    pub fn max(self, other: &Self) -> Self where T: Clone + PartialOrd {
+                        let [x, y, z] = self.0;
+                        let [ox, oy, oz] = other.0.clone();
+                        Self([
+                            if ox > x { ox } else { x },
+                            if oy > y { oy } else { y },
+                            if oz > z { oz } else { z },
+                        ])
+                    }
+
+                    /// Clamps each component to the range given by the respective components
+                    /// of `lo` and `hi`.
+                    ///
+                    /// If a component of `lo` exceeds that of `hi`, the result is that of `hi`.
+                    /// This takes `self` by value so that it takes precedence over the lexicographic
+                    /// [`Ord::clamp`] in method resolution; a `&self` receiver would resolve to
+                    /// [`Ord::clamp`] for frames of `Ord` types. Clone the coordinate first to keep it.
+                    pub fn clamp(self, lo: &Self, hi: &Self) -> Self where T: Clone + PartialOrd {
+                        self.max(lo).min(hi)
+                    }
+
+                    /// Calculates the squared norm of the components using saturating arithmetic.
+                    pub fn saturating_norm_sq(&self) -> T where T: Clone + SaturatingArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        x.clone()
+                            .saturating_mul(x)
+                            .saturating_add(y.clone().saturating_mul(y))
+                            .saturating_add(z.clone().saturating_mul(z))
+                    }
+
+                    /// Calculates the norm of the components using saturating arithmetic and the
+                    /// integer square root, i.e. the result is rounded down.
+                    ///
+                    /// Since the squared norm saturates, the result is capped at the square root
+                    /// of the maximum value of `T`.
+                    pub fn saturating_norm(&self) -> T
+                    where
+                        T: Clone + SaturatingArithmetic<Output = T> + IntegerSqrt<Output = T>
+                    {
+                        self.saturating_norm_sq().isqrt()
                     }
 
                     /// Calculates the cross product (outer product) of two coordinates.
                     ///
                     /// ## Panics
                     /// This operation may overflow.
-                    pub fn cross(&self, rhs: &Self) -> Self where T: Clone + core::ops::Mul<T, Output = T> + core::ops::Sub<T, Output = T> {
-                        Self([
-                            self[1].clone() * rhs[2].clone() - self[2].clone() * rhs[1].clone(),
-                            self[2].clone() * rhs[0].clone() - self[0].clone() * rhs[2].clone(),
-                            self[0].clone() * rhs[1].clone() - self[1].clone() * rhs[0].clone()
-                        ])
+                    pub fn cross(&self, rhs: &Self) -> Self
+                    where
+                        T: core::ops::Sub<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let [x, y, z] = &self.0;
+                        let [rx, ry, rz] = &rhs.0;
+                        Self([y * rz - z * ry, z * rx - x * rz, x * ry - y * rx])
                     }
 
                     /// Calculates the dot product (inner product) of two coordinates.
                     ///
                     /// ## Panics
                     /// This operation may overflow.
-                    pub fn dot(&self, rhs: &Self) -> T where T: Clone + core::ops::Mul<T, Output = T> + core::ops::Add<T, Output = T> {
-                        self[0].clone() * rhs[0].clone() + self[1].clone() * rhs[1].clone() + self[2].clone() * rhs[2].clone()
+                    pub fn dot(&self, rhs: &Self) -> T
+                    where
+                        T: core::ops::Add<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let [x, y, z] = &self.0;
+                        let [rx, ry, rz] = &rhs.0;
+                        x * rx + y * ry + z * rz
+                    }
+
+                    /// Subtracts a scalar from each component using saturating arithmetic, i.e.
+                    /// clamping at the numeric bounds instead of under- or overflowing.
+                    pub fn saturating_sub_scalar(&self, s: T) -> Self where T: Clone + SaturatingArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        Self::new(
+                            x.saturating_sub(s.clone()),
+                            y.saturating_sub(s.clone()),
+                            z.saturating_sub(s),
+                        )
+                    }
+
+                    /// Subtracts a scalar from each component using checked arithmetic.
+                    ///
+                    /// Returns `None` if any component under- or overflows.
+                    pub fn checked_sub_scalar(&self, s: T) -> Option<Self> where T: Clone + CheckedArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        Some(Self::new(
+                            x.checked_sub(s.clone())?,
+                            y.checked_sub(s.clone())?,
+                            z.checked_sub(s)?,
+                        ))
+                    }
+
+                    /// Calculates the dot product of two coordinates using checked arithmetic.
+                    ///
+                    /// Returns `None` if any intermediate step overflows.
+                    pub fn checked_dot(&self, rhs: &Self) -> Option<T> where T: Clone + CheckedArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        x.checked_mul(x2)?
+                            .checked_add(y.checked_mul(y2)?)?
+                            .checked_add(z.checked_mul(z2)?)
+                    }
+
+                    /// Calculates the dot product of two coordinates using saturating arithmetic.
+                    pub fn saturating_dot(&self, rhs: &Self) -> T where T: Clone + SaturatingArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        x.saturating_mul(x2)
+                            .saturating_add(y.saturating_mul(y2))
+                            .saturating_add(z.saturating_mul(z2))
+                    }
+
+                    /// Calculates the cross product of two coordinates using checked arithmetic
+                    /// like [`cross`](Self::cross).
+                    ///
+                    /// Returns `None` if any intermediate product or difference overflows.
+                    pub fn checked_cross(&self, rhs: &Self) -> Option<Self> where T: Clone + CheckedArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        Some(Self([
+                            y.clone().checked_mul(z2.clone())?.checked_sub(z.clone().checked_mul(y2.clone())?)?,
+                            z.checked_mul(x2.clone())?.checked_sub(x.clone().checked_mul(z2)?)?,
+                            x.checked_mul(y2)?.checked_sub(y.checked_mul(x2)?)?,
+                        ]))
+                    }
+
+                    /// Calculates the cross product of two coordinates using saturating arithmetic
+                    /// like [`cross`](Self::cross), i.e. clamping at the numeric bounds instead of
+                    /// overflowing.
+                    pub fn saturating_cross(&self, rhs: &Self) -> Self where T: Clone + SaturatingArithmetic<Output = T> {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        Self([
+                            y.clone().saturating_mul(z2.clone()).saturating_sub(z.clone().saturating_mul(y2.clone())),
+                            z.saturating_mul(x2.clone()).saturating_sub(x.clone().saturating_mul(z2)),
+                            x.saturating_mul(y2).saturating_sub(y.saturating_mul(x2)),
+                        ])
                     }
 
-                    /// Applies a mapping function to each component.
-                    pub fn map<F>(&self, mut map: F) -> Self
+                    /// Calculates the squared Euclidean distance between two coordinates using checked
+                    /// arithmetic.
+                    ///
+                    /// Returns `None` if any intermediate step overflows.
+                    pub fn checked_distance_sq(&self, other: &Self) -> Option<T>
                     where
-                        F: FnMut(T) -> T,
-                        T: Clone
+                        T: Clone + PartialOrd + CheckedArithmetic<Output = T>
+                    {
+                        let delta = |a: &T, b: &T| {
+                            if a >= b {
+                                a.clone().checked_sub(b.clone())
+                            } else {
+                                b.clone().checked_sub(a.clone())
+                            }
+                        };
+
+                        let x = delta(&self.0[0], &other.0[0])?;
+                        let y = delta(&self.0[1], &other.0[1])?;
+                        let z = delta(&self.0[2], &other.0[2])?;
+                        x.clone()
+                            .checked_mul(x)?
+                            .checked_add(y.clone().checked_mul(y)?)?
+                            .checked_add(z.clone().checked_mul(z)?)
+                    }
+
+                    /// Calculates the absolute differences of the components of two coordinates.
+                    ///
+                    /// The smaller value is subtracted from the larger one, so this also works for
+                    /// unsigned types.
+                    fn abs_diff(&self, other: &Self) -> [T; 3]
+                    where
+                        T: Clone + PartialOrd + core::ops::Sub<T, Output = T>
                     {
-                        let x = map(self.x());
-                        let y = map(self.y());
-                        let z = map(self.z());
+                        let delta = |a: &T, b: &T| {
+                            if a >= b {
+                                a.clone() - b.clone()
+                            } else {
+                                b.clone() - a.clone()
+                            }
+                        };
+
+                        [
+                            delta(&self.0[0], &other.0[0]),
+                            delta(&self.0[1], &other.0[1]),
+                            delta(&self.0[2], &other.0[2]),
+                        ]
+                    }
+
+                    /// Calculates the squared Euclidean distance between two coordinates.
+                    ///
+                    /// ## Panics
+                    /// This operation may overflow. See
+                    /// [`checked_distance_sq`](Self::checked_distance_sq) for a checked variant.
+                    pub fn distance_squared(&self, other: &Self) -> T
+                    where
+                        T: Clone + PartialOrd + core::ops::Sub<T, Output = T>
+                            + core::ops::Add<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        Self(self.abs_diff(other)).norm_sq()
+                    }
+
+                    /// Calculates the Euclidean distance between two coordinates.
+                    pub fn distance(&self, other: &Self) -> T
+                    where
+                        T: Clone + PartialOrd + Sqrt<Output = T> + core::ops::Sub<T, Output = T>
+                            + core::ops::Add<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        self.distance_squared(other).sqrt()
+                    }
+
+                    /// Calculates the L1 (Manhattan) distance between two coordinates, i.e. the
+                    /// sum of the absolute component differences.
+                    pub fn distance_l1(&self, other: &Self) -> T
+                    where
+                        T: Clone + PartialOrd + core::ops::Sub<T, Output = T> + core::ops::Add<T, Output = T>
+                    {
+                        let [x, y, z] = self.abs_diff(other);
+                        x + y + z
+                    }
+
+                    /// Calculates the L∞ (Chebyshev) distance between two coordinates, i.e. the
+                    /// maximum absolute component difference.
+                    pub fn distance_linf(&self, other: &Self) -> T
+                    where
+                        T: Clone + PartialOrd + core::ops::Sub<T, Output = T>
+                    {
+                        let [x, y, z] = self.abs_diff(other);
+                        let max = if y > x { y } else { x };
+                        if z > max { z } else { max }
+                    }
+
+                    /// Returns the unit-length vector pointing in the direction of this coordinate.
+                    ///
+                    /// ## Errors
+                    /// Returns [`ParseCoordinateFrameError::DegenerateVector`] if the vector has a
+                    /// length of zero.
+                    pub fn try_unit(&self) -> Result<Self, ParseCoordinateFrameError>
+                    where
+                        T: Clone + PartialEq + ZeroOne<Output = T> + Sqrt<Output = T>
+                            + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm = self.norm();
+                        if norm == T::zero() {
+                            return Err(ParseCoordinateFrameError::DegenerateVector);
+                        }
+
+                        let [x, y, z] = self.0.clone();
+                        Ok(Self::new(x / norm.clone(), y / norm.clone(), z / norm))
+                    }
+
+                    /// Returns the unit-length vector pointing in the direction of this coordinate.
+                    ///
+                    /// If this coordinate has a length of zero it has no direction and is
+                    /// returned unchanged instead of producing NaN components. See
+                    /// [`try_unit`](Self::try_unit) for a variant reporting this case.
+                    pub fn normalize(&self) -> Self
+                    where
+                        T: Clone + PartialEq + ZeroOne<Output = T> + Sqrt<Output = T>
+                            + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        self.try_unit().unwrap_or_else(|_| Self(self.0.clone()))
+                    }
+
+                    /// Returns the unit-length vector pointing in the direction of this coordinate,
+                    /// or the zero vector if the length is zero or NaN.
+                    ///
+                    /// Unlike [`normalize`](Self::normalize), the result is always either a unit
+                    /// vector or exactly zero.
+                    pub fn normalize_or_zero(&self) -> Self
+                    where
+                        T: Clone + PartialEq + ZeroOne<Output = T> + Sqrt<Output = T>
+                            + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm = self.norm();
+                        #[allow(clippy::eq_op)]
+                        if norm == T::zero() || norm != norm {
+                            return Self([T::zero(), T::zero(), T::zero()]);
+                        }
+
+                        let [x, y, z] = self.0.clone();
+                        Self::new(x / norm.clone(), y / norm.clone(), z / norm)
+                    }
+
+                    /// Returns the unit-length vector pointing in the direction of this coordinate,
+                    /// or `default` if the length does not exceed `epsilon` or is NaN.
+                    pub fn normalize_or(&self, default: Self, epsilon: T) -> Self
+                    where
+                        T: Clone + PartialOrd + Sqrt<Output = T>
+                            + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm = self.norm();
+                        if !(norm > epsilon) {
+                            return default;
+                        }
+
+                        let [x, y, z] = self.0.clone();
+                        Self::new(x / norm.clone(), y / norm.clone(), z / norm)
+                    }
+
+                    /// Determines whether this coordinate has approximately unit length, i.e.
+                    /// whether `|norm_sq - 1| <= tol`.
+                    ///
+                    /// For efficiency, the tolerance is compared against the squared norm, which
+                    /// avoids taking a square root.
+                    pub fn is_unit(&self, tol: T) -> bool
+                    where
+                        T: Clone + PartialOrd + ZeroOne<Output = T>
+                            + core::ops::Add<T, Output = T>
+                            + core::ops::Sub<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm_sq = self.norm_sq();
+                        norm_sq <= T::one() + tol.clone() && norm_sq >= T::one() - tol
+                    }
+
+                    /// Returns a vector pointing in the same direction as this coordinate, scaled
+                    /// to the specified length.
+                    ///
+                    /// If this coordinate has a length of zero it has no direction and is
+                    /// returned unchanged.
+                    pub fn with_norm(&self, length: T) -> Self
+                    where
+                        T: Clone + PartialEq + ZeroOne<Output = T> + Sqrt<Output = T>
+                            + core::ops::Mul<T, Output = T> + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm = self.norm();
+                        if norm == T::zero() {
+                            return Self(self.0.clone());
+                        }
+
+                        let scale = length / norm;
+                        let [x, y, z] = self.0.clone();
+                        Self::new(x * scale.clone(), y * scale.clone(), z * scale)
+                    }
+
+                    /// Linearly interpolates between this coordinate and `other`, i.e. calculates
+                    /// `self + (other - self) * t` for each component.
+                    ///
+                    /// The interpolation parameter `t` is not clamped, so values outside of `0..=1`
+                    /// extrapolate. See [`lerp_clamped`](Self::lerp_clamped) for a clamped variant.
+                    pub fn lerp(&self, other: &Self, t: T) -> Self
+                    where
+                        T: Clone + core::ops::Add<T, Output = T> + core::ops::Sub<T, Output = T>
+                            + core::ops::Mul<T, Output = T>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = other.0.clone();
+                        Self::new(
+                            x.clone() + (x2 - x) * t.clone(),
+                            y.clone() + (y2 - y) * t.clone(),
+                            z.clone() + (z2 - z) * t,
+                        )
+                    }
+
+                    /// Linearly interpolates between this coordinate and `other` like
+                    /// [`lerp`](Self::lerp), but clamps the interpolation parameter `t` to `0..=1`.
+                    pub fn lerp_clamped(&self, other: &Self, t: T) -> Self
+                    where
+                        T: Clone + PartialOrd + ZeroOne<Output = T> + core::ops::Add<T, Output = T>
+                            + core::ops::Sub<T, Output = T> + core::ops::Mul<T, Output = T>
+                    {
+                        let t = if t < T::zero() {
+                            T::zero()
+                        } else if t > T::one() {
+                            T::one()
+                        } else {
+                            t
+                        };
+                        self.lerp(other, t)
+                    }
+
+                    /// Calculates the geometric mean of the components, i.e. `(x * y * z).cbrt()`.
+                    ///
+                    /// This is useful e.g. for estimating an isotropic scale from per-axis scale
+                    /// factors. The geometric mean is only defined for positive values; if any
+                    /// component is zero, negative or NaN, NaN is returned.
+                    pub fn geometric_mean(&self) -> T
+                    where
+                        T: Clone + PartialOrd + ZeroOne<Output = T> + Cbrt<Output = T>
+                            + core::ops::Mul<T, Output = T> + core::ops::Div<T, Output = T>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        let zero = T::zero();
+                        if !(x > zero && y > zero && z > zero) {
+                            // Zero divided by zero is NaN for floating-point types.
+                            return zero.clone() / zero;
+                        }
+
+                        (x * y * z).cbrt()
+                    }
+
+                    /// Calculates the cosine similarity of two coordinates, i.e. the cosine of the
+                    /// angle between them.
+                    ///
+                    /// The result is clamped to the range `-1..=1`. If either coordinate has a
+                    /// length of zero, zero is returned.
+                    pub fn cosine_similarity(&self, rhs: &Self) -> T
+                    where
+                        T: Clone + PartialOrd + ZeroOne<Output = T> + Sqrt<Output = T>
+                            + core::ops::Mul<T, Output = T> + core::ops::Add<T, Output = T>
+                            + core::ops::Div<T, Output = T> + core::ops::Neg<Output = T>,
+                        for<'a> &'a T: core::ops::Mul<&'a T, Output = T>,
+                    {
+                        let norm = self.norm() * rhs.norm();
+                        if norm == T::zero() {
+                            return T::zero();
+                        }
+
+                        let cosine = self.dot(rhs) / norm;
+                        if cosine > T::one() {
+                            T::one()
+                        } else if cosine < -T::one() {
+                            -T::one()
+                        } else {
+                            cosine
+                        }
+                    }
+
+                    /// Calculates the magnitude of the horizontal part of this coordinate, i.e.
+                    /// the length of the vector ignoring the vertical component.
+                    pub fn horizontal_norm(&self) -> T
+                    where
+                        T: Clone + Hypot<Output = T>
+                    {
+                        let [first, second] = [#(self.0[#horizontal_indices].clone()),*];
+                        first.hypot(second)
+                    }
+
+                    /// Converts this coordinate to the frame `F`, ensuring that the vertical
+                    /// direction is preserved, i.e. that up stays up and down stays down.
+                    ///
+                    /// ## Errors
+                    /// Returns [`ParseCoordinateFrameError::VerticalMismatch`] if the native
+                    /// vertical direction of `F` opposes the one of this frame, e.g. when
+                    /// converting from [`NorthEastDown`] to [`EastNorthUp`].
+                    pub fn to_frame_checked_vertical<F>(&self) -> Result<F, ParseCoordinateFrameError>
+                    where
+                        F: CoordinateFrame<Type = T> + AxisPermutation + From<NorthEastDown<T>>,
+                        T: Copy + SaturatingNeg<Output = T>
+                    {
+                        let (_, own_signs) = <Self as AxisPermutation>::TO_NED;
+                        let (_, target_signs) = F::TO_NED;
+                        if own_signs[2] != target_signs[2] {
+                            return Err(ParseCoordinateFrameError::VerticalMismatch);
+                        }
+
+                        Ok(F::from(self.to_ned()))
+                    }
+
+                    /// Converts this coordinate to [`NorthEastDown`] and applies a correction
+                    /// rotation, e.g. to compensate for a sensor's mounting misalignment.
+                    ///
+                    /// The correction is a row-major matrix that is multiplied from the left with
+                    /// the NED vector.
+                    pub fn to_ned_with_correction(&self, correction: &[[T; 3]; 3]) -> NorthEastDown<T>
+                    where
+                        T: Copy + SaturatingNeg<Output = T>
+                            + core::ops::Mul<T, Output = T> + core::ops::Add<T, Output = T>
+                    {
+                        let [north, east, down] = self.to_ned().0;
+                        let [a, b, c] = correction.map(|row| {
+                            row[0] * north + row[1] * east + row[2] * down
+                        });
+                        NorthEastDown::new(a, b, c)
+                    }
+
+                    /// Calculates the heading of this coordinate in radians, i.e. the angle of its
+                    /// horizontal part measured from north toward east, in the range `-π..=π`.
+                    pub fn heading(&self) -> T
+                    where
+                        T: Copy + SaturatingNeg<Output = T> + Trigonometry<Output = T>
+                    {
+                        self.east().atan2(self.north())
+                    }
+
+                    /// Calculates the shortest angular difference from this coordinate's
+                    /// [`heading`](Self::heading) to the heading of `other` in radians, wrapped to
+                    /// the range `-π..=π`.
+                    ///
+                    /// Positive values indicate that `other` is heading further toward east,
+                    /// i.e. clockwise when viewed from above.
+                    pub fn heading_diff(&self, other: &Self) -> T
+                    where
+                        T: Copy + PartialOrd + SaturatingNeg<Output = T> + Trigonometry<Output = T>
+                            + core::ops::Add<T, Output = T> + core::ops::Sub<T, Output = T>
+                    {
+                        let pi = T::pi();
+                        let difference = other.heading() - self.heading();
+                        if difference > pi {
+                            difference - (pi + pi)
+                        } else if difference < pi.saturating_neg() {
+                            difference + (pi + pi)
+                        } else {
+                            difference
+                        }
+                    }
+
+                    /// Consumes self and applies a mapping function to each component, e.g. to
+                    /// change the component type while preserving the coordinate frame.
+                    pub fn map<U, F>(self, mut map: F) -> #variant_name <U>
+                    where
+                        F: FnMut(T) -> U
+                    {
+                        let [x, y, z] = self.0;
+                        #variant_name ([map(x), map(y), map(z)])
+                    }
+
+                    /// Applies a mapping function to a reference of each component.
+                    pub fn map_ref<U, F>(&self, map: F) -> #variant_name <U>
+                    where
+                        F: Fn(&T) -> U
+                    {
+                        let [x, y, z] = &self.0;
+                        #variant_name ([map(x), map(y), map(z)])
+                    }
+
+                    /// Calculates the centroid (mean) of the coordinates, or `None` if the
+                    /// iterator is empty.
+                    ///
+                    /// ## Panics
+                    /// The intermediate sum may overflow.
+                    pub fn centroid<I>(iter: I) -> Option<Self>
+                    where
+                        I: IntoIterator<Item = Self>,
+                        T: ZeroOne<Output = T> + core::ops::Add<T, Output = T> + core::ops::Div<T, Output = T> + Clone
+                    {
+                        let mut count = T::zero();
+                        let mut empty = true;
+                        let sum: Self = iter.into_iter().inspect(|_| {
+                            count = count.clone() + T::one();
+                            empty = false;
+                        }).sum();
+                        if empty {
+                            return None;
+                        }
+                        let [x, y, z] = sum.0;
+                        Some(Self([x / count.clone(), y / count.clone(), z / count]))
+                    }
+
+                    /// Applies a separate function to each component, e.g. per-axis calibrations.
+                    ///
+                    /// The functions are given in `x`, `y`, `z` order. Non-capturing closures
+                    /// coerce to function pointers, so `[|x| x + 1, |y| y * 2, |z| z - 1]` works.
+                    pub fn with_each(self, fns: [fn(T) -> T; 3]) -> Self {
+                        let [x, y, z] = self.0;
+                        Self([fns[0](x), fns[1](y), fns[2](z)])
+                    }
+
+                    /// Losslessly widens each component to `f64`, e.g. for plotting.
+                    pub fn to_f64(&self) -> #variant_name <f64>
+                    where
+                        T: Clone + Into<f64>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        #variant_name ::new(x.into(), y.into(), z.into())
+                    }
+
+                    /// Applies a mapping function to the component of the specified axis.
+                    pub fn map_axis<F>(self, axis: Axis, map: F) -> Self
+                    where
+                        F: FnOnce(T) -> T
+                    {
+                        let [x, y, z] = self.0;
+                        match axis {
+                            Axis::X => Self::new(map(x), y, z),
+                            Axis::Y => Self::new(x, map(y), z),
+                            Axis::Z => Self::new(x, y, map(z)),
+                        }
+                    }
+
+                    /// Rotates this coordinate by the specified rotation.
+                    ///
+                    /// This is a rotation within the coordinate frame, i.e. the result is expressed
+                    /// in the same frame as this instance. It is not a change of frame; use the
+                    /// [`From`] conversions for that.
+                    #[cfg(feature = "nalgebra")]
+                    #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+                    pub fn rotated(&self, rotation: &nalgebra::Rotation3<T>) -> Self
+                    where
+                        T: nalgebra::Scalar,
+                        for<'a> &'a nalgebra::Rotation3<T>: core::ops::Mul<nalgebra::Vector3<T>, Output = nalgebra::Vector3<T>>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        let rotated = rotation * nalgebra::Vector3::new(x, y, z);
+                        let [x, y, z]: [T; 3] = rotated.into();
                         Self::new(x, y, z)
                     }
 
+                    /// Applies the specified rotation and returns the raw `[x, y, z]` components.
+                    ///
+                    /// Unlike [`rotated`](Self::rotated), the result is not considered to be
+                    /// in this frame anymore: an arbitrary rotation generally does not map onto
+                    /// any of the axis-aligned frames, which is what
+                    /// [`CoordinateFrameType::Other`] stands for. Use
+                    /// `DynamicFrame::new(CoordinateFrameType::Other, components)` to keep the
+                    /// result tagged accordingly.
+                    #[cfg(feature = "nalgebra")]
+                    #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+                    pub fn rotate(&self, q: &nalgebra::UnitQuaternion<T>) -> [T; 3]
+                    where
+                        T: nalgebra::Scalar,
+                        for<'a> &'a nalgebra::UnitQuaternion<T>: core::ops::Mul<nalgebra::Vector3<T>, Output = nalgebra::Vector3<T>>
+                    {
+                        let [x, y, z] = self.0.clone();
+                        (q * nalgebra::Vector3::new(x, y, z)).into()
+                    }
+
                     #(#components_impl)*
                 }
 
@@ -574,6 +1948,20 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         self.to_enu()
                     }
 
+                    /// Consumes self and converts it to a [`NorthEastDown`] instance.
+                    fn into_ned(self) -> NorthEastDown<Self::Type>
+                    where
+                        Self::Type: SaturatingNeg<Output = Self::Type> {
+                        self.into_ned()
+                    }
+
+                    /// Consumes self and converts it to an [`EastNorthUp`] instance.
+                    fn into_enu(self) -> EastNorthUp<Self::Type>
+                    where
+                        Self::Type: SaturatingNeg<Output = Self::Type> {
+                        self.into_enu()
+                    }
+
                     /// Gets the value of the first dimension.
                     #[doc = #x_doc]
                     fn x(&self) -> Self::Type where Self::Type: Clone {
@@ -655,6 +2043,64 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl #variant_name <f32> {
+                    /// Returns the raw bit patterns of the components.
+                    ///
+                    /// This is bit-exact, i.e. NaN payloads and signed zeros are preserved.
+                    pub fn to_bits(&self) -> [u32; 3] {
+                        let [x, y, z] = self.0;
+                        [x.to_bits(), y.to_bits(), z.to_bits()]
+                    }
+
+                    /// Constructs a coordinate from the raw bit patterns of its components.
+                    ///
+                    /// This is the inverse of [`to_bits`](Self::to_bits).
+                    pub fn from_bits(bits: [u32; 3]) -> Self {
+                        let [x, y, z] = bits;
+                        Self([f32::from_bits(x), f32::from_bits(y), f32::from_bits(z)])
+                    }
+                }
+
+                impl #variant_name <f64> {
+                    /// Returns the raw bit patterns of the components.
+                    ///
+                    /// This is bit-exact, i.e. NaN payloads and signed zeros are preserved.
+                    pub fn to_bits(&self) -> [u64; 3] {
+                        let [x, y, z] = self.0;
+                        [x.to_bits(), y.to_bits(), z.to_bits()]
+                    }
+
+                    /// Constructs a coordinate from the raw bit patterns of its components.
+                    ///
+                    /// This is the inverse of [`to_bits`](Self::to_bits).
+                    pub fn from_bits(bits: [u64; 3]) -> Self {
+                        let [x, y, z] = bits;
+                        Self([f64::from_bits(x), f64::from_bits(y), f64::from_bits(z)])
+                    }
+                }
+
+                impl<T> From<#variant_name <T>> for DynamicFrame<T> {
+                    fn from(value: #variant_name <T>) -> DynamicFrame<T> {
+                        DynamicFrame::new(#variant_name ::<T>::COORDINATE_FRAME, value.0)
+                    }
+                }
+
+                impl<T> core::convert::TryFrom<DynamicFrame<T>> for #variant_name <T> {
+                    type Error = ParseCoordinateFrameError;
+
+                    fn try_from(value: DynamicFrame<T>) -> Result<#variant_name <T>, Self::Error> {
+                        if value.frame() == Self::COORDINATE_FRAME {
+                            Ok(Self(value.into_components()))
+                        } else {
+                            Err(ParseCoordinateFrameError::FrameMismatch)
+                        }
+                    }
+                }
+
+                impl<T> AxisPermutation for #variant_name <T> {
+                    const TO_NED: ([usize; 3], [i8; 3]) = ([#(#ned_indices),*], [#(#ned_signs),*]);
+                }
+
                 impl<T> From<#variant_name <T>> for [T; 3] {
                     fn from(value: #variant_name <T>) -> [T; 3] {
                         value.0
@@ -668,6 +2114,21 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::convert::TryFrom<&[T]> for #variant_name <T> where T: Clone {
+                    type Error = ParseCoordinateFrameError;
+
+                    /// Constructs an instance from a slice of exactly three components.
+                    fn try_from(value: &[T]) -> Result<#variant_name <T>, Self::Error> {
+                        match value {
+                            [x, y, z] => Ok(Self([x.clone(), y.clone(), z.clone()])),
+                            _ => Err(ParseCoordinateFrameError::WrongLength {
+                                expected: 3,
+                                got: value.len(),
+                            }),
+                        }
+                    }
+                }
+
                 impl<T> From<[T; 3]> for #variant_name <T> {
                     fn from(value: [T; 3]) -> #variant_name <T> {
                         #variant_name (value)
@@ -776,6 +2237,24 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                #[cfg(feature = "micromath")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "micromath")))]
+                impl #variant_name <u32> {
+                    /// Converts to a [`U8x3`](micromath::vector::U8x3), clamping components
+                    /// larger than [`u8::MAX`] to [`u8::MAX`].
+                    pub fn to_u8x3_saturating(&self) -> micromath::vector::U8x3 {
+                        let [x, y, z] = self.0.map(|value| u8::try_from(value).unwrap_or(u8::MAX));
+                        micromath::vector::U8x3 { x, y, z }
+                    }
+
+                    /// Converts to a [`U8x3`](micromath::vector::U8x3), keeping only the lowest
+                    /// eight bits of each component.
+                    pub fn to_u8x3_wrapping(&self) -> micromath::vector::U8x3 {
+                        let [x, y, z] = self.0.map(|value| value as u8);
+                        micromath::vector::U8x3 { x, y, z }
+                    }
+                }
+
                 #[cfg(feature = "micromath")]
                 #[cfg_attr(docsrs, doc(cfg(feature = "micromath")))]
                 impl From<micromath::vector::I8x3> for #variant_name <i8> {
@@ -793,6 +2272,12 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::convert::AsRef<#variant_name <T>> for #variant_name <T> {
+                    fn as_ref(&self) -> &#variant_name <T> {
+                        self
+                    }
+                }
+
                 impl<T> core::convert::AsRef<[T; 3]> for #variant_name <T> {
                     fn as_ref(&self) -> &[T; 3] {
                         &self.0
@@ -817,6 +2302,29 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::borrow::Borrow<[T; 3]> for #variant_name <T> {
+                    fn borrow(&self) -> &[T; 3] {
+                        &self.0
+                    }
+                }
+
+                impl<T> core::borrow::BorrowMut<[T; 3]> for #variant_name <T> {
+                    fn borrow_mut(&mut self) -> &mut [T; 3] {
+                        &mut self.0
+                    }
+                }
+
+                /// Hashes the three components in order, exactly like the `[T; 3]` array does.
+                ///
+                /// This is consistent with [`PartialEq`], which compares the components in
+                /// order, and with the [`Borrow<[T; 3]>`](core::borrow::Borrow) implementation,
+                /// which allows looking up frames in hash-based collections by their components.
+                impl<T> core::hash::Hash for #variant_name <T> where T: core::hash::Hash {
+                    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                        self.0.hash(state)
+                    }
+                }
+
                 impl<T> core::ops::Deref for #variant_name <T> {
                     type Target = [T; 3];
 
@@ -831,12 +2339,84 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> IntoIterator for #variant_name <T> {
+                    type Item = T;
+                    type IntoIter = core::array::IntoIter<T, 3>;
+
+                    /// Consumes self and iterates the components in `x`, `y`, `z` order.
+                    fn into_iter(self) -> Self::IntoIter {
+                        self.0.into_iter()
+                    }
+                }
+
+                impl<'a, T> IntoIterator for &'a #variant_name <T> {
+                    type Item = &'a T;
+                    type IntoIter = core::slice::Iter<'a, T>;
+
+                    /// Iterates references to the components in `x`, `y`, `z` order.
+                    fn into_iter(self) -> Self::IntoIter {
+                        self.0.iter()
+                    }
+                }
+
+                impl<'a, T> IntoIterator for &'a mut #variant_name <T> {
+                    type Item = &'a mut T;
+                    type IntoIter = core::slice::IterMut<'a, T>;
+
+                    /// Iterates mutable references to the components in `x`, `y`, `z` order.
+                    fn into_iter(self) -> Self::IntoIter {
+                        self.0.iter_mut()
+                    }
+                }
+
+                impl<T> core::iter::Sum for #variant_name <T>
+                where
+                    T: ZeroOne<Output = T> + core::ops::Add<T, Output = T>
+                {
+                    /// Sums the coordinates component-wise, starting from zero.
+                    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                        iter.fold(Self([T::zero(), T::zero(), T::zero()]), |sum, value| {
+                            let [x, y, z] = sum.0;
+                            let [x2, y2, z2] = value.0;
+                            Self([x + x2, y + y2, z + z2])
+                        })
+                    }
+                }
+
+                impl<'a, T> core::iter::Sum<&'a #variant_name <T>> for #variant_name <T>
+                where
+                    T: ZeroOne<Output = T> + core::ops::Add<T, Output = T> + Clone
+                {
+                    /// Sums the coordinates component-wise, starting from zero.
+                    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                        iter.cloned().sum()
+                    }
+                }
+
+                impl<T> core::iter::FromIterator<T> for #variant_name <T> {
+                    /// Constructs an instance from the first three items of the iterator.
+                    ///
+                    /// ## Panics
+                    /// Panics if the iterator yields fewer than three items. Use the
+                    /// `try_from_iter` function to handle this case gracefully.
+                    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                        match Self::try_from_iter(iter) {
+                            Ok(value) => value,
+                            Err(_) => panic!("The provided iterator must yield at least 3 items"),
+                        }
+                    }
+                }
+
                 impl<T> core::cmp::PartialEq<&[T; 3]> for #variant_name <T> where T: core::cmp::PartialEq<T> {
                     fn eq(&self, other: &&[T; 3]) -> bool {
                         self.0.eq(*other)
                     }
                 }
 
+                #index_impl
+
+                #(#const_integer_conversions)*
+
                 #(#handedness_impl)*
                 #(#conversion_impl)*
 
@@ -865,27 +2445,88 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
 
                 #[cfg(feature = "nalgebra")]
                 #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
-                impl<T> core::convert::From<nalgebra::Vector3<T>> for #variant_name <T>
+                impl<T> core::convert::From<nalgebra::Vector3<T>> for #variant_name <T>
+                where
+                    T: nalgebra::Scalar + Copy
+                {
+                    fn from(value: nalgebra::Vector3<T>) -> #variant_name <T> {
+                        Self::new(value.x, value.y, value.z)
+                    }
+                }
+
+                #[cfg(feature = "nalgebra")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+                impl<T> core::convert::From<#variant_name <T>> for nalgebra::Vector3<T>
+                where
+                    T: nalgebra::Scalar
+                {
+                    fn from(value: #variant_name <T>) -> nalgebra::Vector3<T> {
+                        let [x, y, z] = value.0;
+                        Self::new(x, y, z)
+                    }
+                }
+
+                #[cfg(feature = "nalgebra")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
+                impl<T> core::ops::Mul<#variant_name <T>> for nalgebra::Rotation3<T>
                 where
-                    T: nalgebra::Scalar + Copy
+                    T: nalgebra::Scalar,
+                    for<'a> &'a nalgebra::Rotation3<T>: core::ops::Mul<nalgebra::Vector3<T>, Output = nalgebra::Vector3<T>>
                 {
-                    fn from(value: nalgebra::Vector3<T>) -> #variant_name <T> {
+                    type Output = #variant_name <T>;
+
+                    fn mul(self, rhs: #variant_name <T>) -> Self::Output {
+                        rhs.rotated(&self)
+                    }
+                }
+
+                #[cfg(feature = "mint")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+                impl<T> core::convert::From<mint::Vector3<T>> for #variant_name <T> {
+                    fn from(value: mint::Vector3<T>) -> #variant_name <T> {
                         Self::new(value.x, value.y, value.z)
                     }
                 }
 
-                #[cfg(feature = "nalgebra")]
-                #[cfg_attr(docsrs, doc(cfg(feature = "nalgebra")))]
-                impl<T> core::convert::From<#variant_name <T>> for nalgebra::Vector3<T>
-                where
-                    T: nalgebra::Scalar
-                {
-                    fn from(value: #variant_name <T>) -> nalgebra::Vector3<T> {
+                #[cfg(feature = "mint")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+                impl<T> core::convert::From<#variant_name <T>> for mint::Vector3<T> {
+                    fn from(value: #variant_name <T>) -> mint::Vector3<T> {
                         let [x, y, z] = value.0;
-                        Self::new(x, y, z)
+                        Self { x, y, z }
+                    }
+                }
+
+                #[cfg(feature = "mint")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+                impl<T> core::convert::From<mint::Point3<T>> for #variant_name <T> {
+                    fn from(value: mint::Point3<T>) -> #variant_name <T> {
+                        Self::new(value.x, value.y, value.z)
+                    }
+                }
+
+                #[cfg(feature = "mint")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "mint")))]
+                impl<T> core::convert::From<#variant_name <T>> for mint::Point3<T> {
+                    fn from(value: #variant_name <T>) -> mint::Point3<T> {
+                        let [x, y, z] = value.0;
+                        Self { x, y, z }
                     }
                 }
 
+                // SAFETY: The type is `#[repr(C)]` around a single `[T; 3]` field; it has
+                // no padding and every bit pattern is valid whenever it is valid for `T`.
+                #[cfg(feature = "bytemuck")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+                #[allow(unsafe_code)]
+                unsafe impl<T> bytemuck::Zeroable for #variant_name <T> where T: bytemuck::Zeroable {}
+
+                // SAFETY: See the `Zeroable` implementation above.
+                #[cfg(feature = "bytemuck")]
+                #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+                #[allow(unsafe_code)]
+                unsafe impl<T> bytemuck::Pod for #variant_name <T> where T: bytemuck::Pod {}
+
                 impl<T> core::ops::Add<T> for #variant_name <T>
                 where
                     T: core::ops::Add<T, Output = T> + Clone
@@ -909,6 +2550,33 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Add<(T, T, T)> for #variant_name <T>
+                where
+                    T: core::ops::Add<T, Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    /// Adds the tuple's values component-wise.
+                    fn add(self, rhs: (T, T, T)) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let (x2, y2, z2) = rhs;
+                        Self::new(x + x2, y + y2, z + z2)
+                    }
+                }
+
+                impl<T> core::ops::AddAssign<(T, T, T)> for #variant_name <T>
+                where
+                    T: core::ops::AddAssign<T>
+                {
+                    /// Adds the tuple's values component-wise.
+                    fn add_assign(&mut self, rhs: (T, T, T)) {
+                        let (x, y, z) = rhs;
+                        self.0[0] += x;
+                        self.0[1] += y;
+                        self.0[2] += z;
+                    }
+                }
+
                 impl<T> core::ops::Add<#variant_name <T>> for #variant_name <T>
                 where
                     T: core::ops::Add<T, Output = T> + Clone
@@ -922,6 +2590,32 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Add<&#variant_name <T>> for #variant_name <T>
+                where
+                    T: core::ops::Add<T, Output = T> + Clone
+                {
+                    type Output = #variant_name <T>;
+
+                    fn add(self, rhs: &#variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let [x2, y2, z2] = rhs.0.clone();
+                        Self::new(x + x2, y + y2, z + z2)
+                    }
+                }
+
+                impl<T> core::ops::Add<&#variant_name <T>> for &#variant_name <T>
+                where
+                    T: core::ops::Add<T, Output = T> + Clone
+                {
+                    type Output = #variant_name <T>;
+
+                    fn add(self, rhs: &#variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        #variant_name ::new(x + x2, y + y2, z + z2)
+                    }
+                }
+
                 impl<T> core::ops::Sub<T> for #variant_name <T>
                 where
                     T: core::ops::Sub<T, Output = T> + Clone
@@ -945,6 +2639,33 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Sub<(T, T, T)> for #variant_name <T>
+                where
+                    T: core::ops::Sub<T, Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    /// Subtracts the tuple's values component-wise.
+                    fn sub(self, rhs: (T, T, T)) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let (x2, y2, z2) = rhs;
+                        Self::new(x - x2, y - y2, z - z2)
+                    }
+                }
+
+                impl<T> core::ops::SubAssign<(T, T, T)> for #variant_name <T>
+                where
+                    T: core::ops::SubAssign<T>
+                {
+                    /// Subtracts the tuple's values component-wise.
+                    fn sub_assign(&mut self, rhs: (T, T, T)) {
+                        let (x, y, z) = rhs;
+                        self.0[0] -= x;
+                        self.0[1] -= y;
+                        self.0[2] -= z;
+                    }
+                }
+
                 impl<T> core::ops::Sub<#variant_name <T>> for #variant_name <T>
                 where
                     T: core::ops::Sub<T, Output = T> + Clone
@@ -958,6 +2679,32 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Sub<&#variant_name <T>> for #variant_name <T>
+                where
+                    T: core::ops::Sub<T, Output = T> + Clone
+                {
+                    type Output = #variant_name <T>;
+
+                    fn sub(self, rhs: &#variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let [x2, y2, z2] = rhs.0.clone();
+                        Self::new(x - x2, y - y2, z - z2)
+                    }
+                }
+
+                impl<T> core::ops::Sub<&#variant_name <T>> for &#variant_name <T>
+                where
+                    T: core::ops::Sub<T, Output = T> + Clone
+                {
+                    type Output = #variant_name <T>;
+
+                    fn sub(self, rhs: &#variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0.clone();
+                        let [x2, y2, z2] = rhs.0.clone();
+                        #variant_name ::new(x - x2, y - y2, z - z2)
+                    }
+                }
+
                 impl<T> core::ops::Mul<T> for #variant_name <T>
                 where
                     T: core::ops::Mul<T, Output = T> + Clone
@@ -970,6 +2717,38 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Mul<[T; 3]> for #variant_name <T>
+                where
+                    T: core::ops::Mul<T, Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    /// Multiplies the components element-wise with the provided factors.
+                    fn mul(self, rhs: [T; 3]) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let [x2, y2, z2] = rhs;
+                        Self::new(x * x2, y * y2, z * z2)
+                    }
+                }
+
+                impl<T> core::ops::Mul<#variant_name <T>> for #variant_name <T>
+                where
+                    T: core::ops::Mul<T, Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    /// Multiplies the components element-wise (Hadamard product).
+                    fn mul(self, rhs: #variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let [x2, y2, z2] = rhs.0;
+                        Self::new(x * x2, y * y2, z * z2)
+                    }
+                }
+
+                #(#left_mul_impl)*
+
+                #(#glam_impl)*
+
                 impl<T> core::ops::MulAssign<T> for #variant_name <T>
                 where
                     T: core::ops::MulAssign<T> + Clone
@@ -993,6 +2772,24 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                     }
                 }
 
+                impl<T> core::ops::Div<#variant_name <T>> for #variant_name <T>
+                where
+                    T: core::ops::Div<T, Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    /// Divides the components element-wise, e.g. to undo per-axis scale factors.
+                    ///
+                    /// This is not a meaningful geometric operation. Division by a zero component
+                    /// behaves like the element type does, i.e. it panics for integers and yields
+                    /// infinity or NaN for floating-point types.
+                    fn div(self, rhs: #variant_name <T>) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        let [x2, y2, z2] = rhs.0;
+                        Self::new(x / x2, y / y2, z / z2)
+                    }
+                }
+
                 impl<T> core::ops::DivAssign<T> for #variant_name <T>
                 where
                     T: core::ops::DivAssign<T> + Clone
@@ -1003,13 +2800,156 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                         self.0[2] /= rhs;
                     }
                 }
+
+                impl<T> core::ops::Neg for #variant_name <T>
+                where
+                    T: core::ops::Neg<Output = T>
+                {
+                    type Output = #variant_name <T>;
+
+                    fn neg(self) -> Self::Output {
+                        let [x, y, z] = self.0;
+                        Self::new(-x, -y, -z)
+                    }
+                }
+
+                impl<'a, T> core::ops::Neg for &'a #variant_name <T>
+                where
+                    T: core::ops::Neg<Output = T> + Clone
+                {
+                    type Output = #variant_name <T>;
+
+                    fn neg(self) -> Self::Output {
+                        let [x, y, z] = self.0.clone();
+                        #variant_name ::new(-x, -y, -z)
+                    }
+                }
+            }
+        }
+    });
+
+    let impls: Vec<_> = impls.collect();
+    let express_count = express_conversions.len();
+
+    // Every pair of physical frames, converted directly and through NED.
+    let enum_name_ref = &enum_name;
+    let conversion_pairs = physical_variants.iter().flat_map(|from| {
+        physical_variants.iter().map(move |to| {
+            quote! {
+                {
+                    let from = #from :: from(values.clone());
+                    let direct: #to <T> = from.clone().into();
+                    let pivot: #to <T> = NorthEastDown::from(from).into();
+                    f(#enum_name_ref :: #from, #enum_name_ref :: #to, direct.into(), pivot.into());
+                }
             }
+        })
+    });
+
+    let base_vector_calls = physical_variants.iter().map(|variant| {
+        quote! {
+            f(#enum_name_ref :: #variant, [#variant ::<T>::x_axis(), #variant ::<T>::y_axis(), #variant ::<T>::z_axis()]);
         }
     });
 
     let expanded = quote! {
         #(#impls)*
 
+        impl #enum_name {
+            /// All physical coordinate frame types, i.e. every type except
+            /// [`Other`](Self::Other) and [`Undefined`](Self::Undefined).
+            pub const PHYSICAL: &'static [#enum_name] = &[
+                #(#enum_name :: #physical_variants),*
+            ];
+
+            /// Returns the name of this coordinate frame type, e.g. `"NorthEastDown"`.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+
+            /// Returns all physical coordinate frame types, i.e. [`PHYSICAL`](Self::PHYSICAL).
+            pub const fn all() -> &'static [#enum_name] {
+                Self::PHYSICAL
+            }
+
+            /// Returns all coordinate frame types, including the special
+            /// [`Other`](Self::Other) and [`Undefined`](Self::Undefined) types.
+            pub const fn all_including_special() -> &'static [#enum_name] {
+                &[#(#enum_name :: #all_variants),*]
+            }
+
+            /// Returns the directions of the three axes of this coordinate frame type, e.g.
+            /// `[North, East, Down]` for [`NorthEastDown`](Self::NorthEastDown), or `None` for
+            /// [`Other`](Self::Other) and [`Undefined`](Self::Undefined).
+            pub const fn components(self) -> Option<[Direction; 3]> {
+                match self {
+                    #(#directions_arms)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the opposite coordinate frame type, i.e. the one with all axes flipped,
+            /// e.g. [`SouthWestUp`](Self::SouthWestUp) for [`NorthEastDown`](Self::NorthEastDown).
+            ///
+            /// [`Other`](Self::Other) and [`Undefined`](Self::Undefined) are returned as-is.
+            pub const fn flipped(self) -> #enum_name {
+                match self {
+                    #(#flipped_arms)*
+                    other => other,
+                }
+            }
+
+            /// Indicates whether this coordinate frame type is right-handed, or `None` for
+            /// [`Other`](Self::Other) and [`Undefined`](Self::Undefined).
+            pub const fn is_right_handed(self) -> Option<bool> {
+                match self {
+                    #(#handedness_arms)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the signed permutation matrix that maps coordinates expressed in the
+            /// `from` frame into the `to` frame, or `None` if either frame type is
+            /// [`Other`](Self::Other) or [`Undefined`](Self::Undefined).
+            ///
+            /// The matrix is row-major and multiplied from the left, i.e. the `i`-th
+            /// component in the `to` frame is `sum(matrix[i][j] * from[j])`.
+            pub const fn conversion_matrix(from: #enum_name, to: #enum_name) -> Option<[[i8; 3]; 3]> {
+                let (Some(from), Some(to)) = (from.ned_matrix(), to.ned_matrix()) else {
+                    return None;
+                };
+
+                // The inverse of a signed permutation matrix is its transpose,
+                // so this calculates `transpose(to) * from`.
+                let mut matrix = [[0; 3]; 3];
+                let mut i = 0;
+                while i < 3 {
+                    let mut j = 0;
+                    while j < 3 {
+                        let mut k = 0;
+                        while k < 3 {
+                            matrix[i][j] += to[k][i] * from[k][j];
+                            k += 1;
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                Some(matrix)
+            }
+
+            /// Returns the signed permutation matrix that maps coordinates expressed in this
+            /// frame into [`NorthEastDown`].
+            const fn ned_matrix(self) -> Option<[[i8; 3]; 3]> {
+                match self {
+                    #(#ned_matrix_arms)*
+                    _ => None
+                }
+            }
+        }
+
         impl From<#enum_name> for u8 {
             fn from(value: #enum_name) -> u8 {
                 value as u8
@@ -1035,12 +2975,52 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
 
         impl core::fmt::Display for #enum_name {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                match self {
-                    #(#display_arms)*
+                f.write_str(self.name())
+            }
+        }
+
+        impl core::str::FromStr for #enum_name {
+            type Err = ParseCoordinateFrameError;
+
+            /// Parses a coordinate frame type from its variant name, e.g. `"NorthEastDown"`.
+            fn from_str(s: &str) -> Result<#enum_name, Self::Err> {
+                match s {
+                    #(#from_str_arms)*
+                    _ => Err(ParseCoordinateFrameError::UnknownVariant)
                 }
             }
         }
 
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl serde::Serialize for #enum_name {
+            /// Serializes the coordinate frame type as its `u8` discriminant.
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer
+            {
+                serializer.serialize_u8(u8::from(self))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+        impl<'de> serde::Deserialize<'de> for #enum_name {
+            /// Deserializes the coordinate frame type from its `u8` discriminant.
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>
+            {
+                let value = u8::deserialize(deserializer)?;
+                #enum_name ::try_from(value).map_err(|_| {
+                    serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(value.into()),
+                        &"a coordinate frame type discriminant",
+                    )
+                })
+            }
+        }
+
         #[cfg(feature = "defmt")]
         #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
         impl defmt::Format for #enum_name {
@@ -1073,12 +3053,137 @@ fn process_unit_enum(enum_name: Ident, data_enum: DataEnum) -> TokenStream {
                 _ => return None
             })
         }
+
+        /// Converts components in the specified coordinate frame to [`NorthEastDown`].
+        ///
+        /// ## Errors
+        /// Returns [`ParseCoordinateFrameError::UnsupportedFrame`] if the coordinate frame type
+        /// is [`Other`](CoordinateFrameType::Other) or [`Undefined`](CoordinateFrameType::Undefined).
+        pub fn convert_to_ned<T>(frame: #enum_name, data: [T; 3]) -> Result<NorthEastDown<T>, ParseCoordinateFrameError>
+        where
+            T: Copy + SaturatingNeg<Output = T>,
+        {
+            match frame {
+                #(#to_ned_arms)*
+                _ => Err(ParseCoordinateFrameError::UnsupportedFrame)
+            }
+        }
+
+        /// Converts components in the specified coordinate frame to [`EastNorthUp`].
+        ///
+        /// ## Errors
+        /// Returns [`ParseCoordinateFrameError::UnsupportedFrame`] if the coordinate frame type
+        /// is [`Other`](CoordinateFrameType::Other) or [`Undefined`](CoordinateFrameType::Undefined).
+        pub fn convert_to_enu<T>(frame: #enum_name, data: [T; 3]) -> Result<EastNorthUp<T>, ParseCoordinateFrameError>
+        where
+            T: Copy + SaturatingNeg<Output = T>,
+        {
+            match frame {
+                #(#to_enu_arms)*
+                _ => Err(ParseCoordinateFrameError::UnsupportedFrame)
+            }
+        }
+
+        /// Returns the handedness of the physical coordinate frame at the specified index
+        /// of the `PHYSICAL` list, e.g. for building constant metadata tables.
+        ///
+        /// ## Panics
+        /// Panics if the index is out of bounds.
+        pub const fn handedness_of(index: usize) -> Handedness {
+            const HANDEDNESS: &[Handedness] = &[#(#handedness_of),*];
+            HANDEDNESS[index]
+        }
+
+        /// Returns the vertical axis of the physical coordinate frame at the specified index
+        /// of the `PHYSICAL` list, e.g. for building constant metadata tables.
+        ///
+        /// ## Panics
+        /// Panics if the index is out of bounds.
+        pub const fn vertical_axis_of(index: usize) -> Axis {
+            const VERTICAL_AXIS: &[Axis] = &[#(#vertical_axis_of),*];
+            VERTICAL_AXIS[index]
+        }
+
+        /// Finds the physical coordinate frame whose signed permutation matrix best matches
+        /// the specified matrix, e.g. to snap a calibrated mounting rotation to the nearest
+        /// axis-aligned frame.
+        ///
+        /// Like [`to_ned_with_matrix`](NorthEastDown::to_ned_with_matrix), the matrix is row-major
+        /// and maps the frame's components to north, east and down. The best match is the
+        /// frame maximizing the Frobenius inner product with `m`; ties resolve to the frame
+        /// that comes first in `PHYSICAL`.
+        pub fn closest_frame<T>(m: &[[T; 3]; 3]) -> #enum_name
+        where
+            T: Copy + SaturatingNeg<Output = T> + core::ops::Add<T, Output = T> + PartialOrd,
+        {
+            let mut best = #enum_name :: NorthEastDown;
+            let mut best_score = None;
+            for &frame in #enum_name :: PHYSICAL {
+                let Some(matrix) = frame.ned_matrix() else {
+                    continue;
+                };
+
+                // Each row has exactly one non-zero entry.
+                let [a, b, c] = [0, 1, 2].map(|row| {
+                    let column = matrix[row].iter().position(|&sign| sign != 0).unwrap_or(0);
+                    let value = m[row][column];
+                    if matrix[row][column] < 0 { value.saturating_neg() } else { value }
+                });
+                let score = a + b + c;
+                if best_score.map_or(true, |best| score > best) {
+                    best = frame;
+                    best_score = Some(score);
+                }
+            }
+            best
+        }
+
+        /// Expresses a [`NorthEastDown`] coordinate in every physical coordinate frame.
+        ///
+        /// The returned iterator yields each coordinate frame type along with the components
+        /// of the coordinate in that frame. This is mainly useful for debugging and teaching.
+        pub fn express_in_all<T>(ned: NorthEastDown<T>) -> impl Iterator<Item = (#enum_name, [T; 3])>
+        where
+            T: Clone + SaturatingNeg<Output = T>,
+        {
+            let conversions: [(#enum_name, fn(NorthEastDown<T>) -> [T; 3]); #express_count] = [
+                #(#express_conversions)*
+            ];
+            conversions
+                .into_iter()
+                .map(move |(frame, convert)| (frame, convert(ned.clone())))
+        }
+
+        /// Converts the specified components between every pair of physical coordinate frames,
+        /// once through the direct [`From`] implementation and once through [`NorthEastDown`].
+        ///
+        /// The callback receives the source and target frame types as well as the components
+        /// obtained from the direct and the NED-pivot conversion, in this order.
+        #[cfg(test)]
+        pub(crate) fn for_each_conversion_pair<T, F>(values: [T; 3], mut f: F)
+        where
+            T: Clone + SaturatingNeg<Output = T>,
+            F: FnMut(#enum_name, #enum_name, [T; 3], [T; 3]),
+        {
+            #(#conversion_pairs)*
+        }
+
+        /// Calls `f` for every physical coordinate frame type along with the base vectors of
+        /// its `x`, `y` and `z` axes.
+        #[cfg(test)]
+        pub(crate) fn for_each_base_vectors<T, F>(mut f: F)
+        where
+            T: ZeroOne<Output = T> + core::ops::Neg<Output = T>,
+            F: FnMut(#enum_name, [[T; 3]; 3]),
+        {
+            #(#base_vector_calls)*
+        }
     };
     TokenStream::from(expanded)
 }
 
 /// Processes an enum and returns an error if it is not unit.
-fn process_enum(name: Ident, data_enum: DataEnum) -> TokenStream {
+fn process_enum(name: Ident, data_enum: DataEnum, options: Options) -> TokenStream {
     let is_unit = data_enum
         .variants
         .iter()
@@ -1095,6 +3200,30 @@ fn process_enum(name: Ident, data_enum: DataEnum) -> TokenStream {
         return TokenStream::from(expanded);
     }
 
+    if options.require_complete {
+        let missing: Vec<_> = all_permutations()
+            .into_iter()
+            .filter(|permutation| {
+                !data_enum
+                    .variants
+                    .iter()
+                    .any(|variant| variant.ident == permutation)
+            })
+            .collect();
+        if !missing.is_empty() {
+            let error_message = format!(
+                "The enum `{}` is missing {} of the 48 coordinate frames: {}",
+                name,
+                missing.len(),
+                missing.join(", ")
+            );
+            let expanded = quote! {
+                compile_error!(#error_message);
+            };
+            return TokenStream::from(expanded);
+        }
+    }
+
     process_unit_enum(name, data_enum)
 }
 
@@ -1107,6 +3236,55 @@ fn error_only_enums() -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Determines, for each of the `targets` directions, the index of the component of the frame
+/// providing it and the sign to apply.
+fn permutation(components: &[String; 3], targets: [&str; 3]) -> [(usize, i8); 3] {
+    targets.map(|target| {
+        components
+            .iter()
+            .enumerate()
+            .find_map(|(i, component)| {
+                if component == target {
+                    Some((i, 1))
+                } else if axis_direction(component) == axis_direction(target) {
+                    Some((i, -1))
+                } else {
+                    None
+                }
+            })
+            .expect("Failed to find axis in coordinate frame")
+    })
+}
+
+/// Builds the UpperCamelCase names of all 48 physical coordinate frames.
+fn all_permutations() -> Vec<String> {
+    const DIRECTIONS: [&str; 6] = ["north", "east", "south", "west", "down", "up"];
+    let exclusive = |a: &str, b: &str| {
+        MUTUALLY_EXCLUSIVE
+            .iter()
+            .any(|pair| pair.contains(&a) && pair.contains(&b))
+    };
+
+    let mut permutations = Vec::new();
+    for x in DIRECTIONS {
+        for y in DIRECTIONS.iter().copied().filter(|&y| !exclusive(x, y)) {
+            for z in DIRECTIONS
+                .iter()
+                .copied()
+                .filter(|&z| !exclusive(x, z) && !exclusive(y, z))
+            {
+                permutations.push(format!(
+                    "{}{}{}",
+                    capitalize(x),
+                    capitalize(y),
+                    capitalize(z)
+                ));
+            }
+        }
+    }
+    permutations
+}
+
 /// Splits an UpperCamelCase string into components
 fn split_variant_name_into_components(input: &str) -> [String; 3] {
     let mut components = Vec::new();
@@ -1190,13 +3368,25 @@ fn axis_def_t(axis: &str) -> impl ToTokens {
         "north" => quote! { [T::zero(), T::one(), T::zero()] },
         "south" => quote! { [T::zero(), T::one(), T::zero()] },
         "east" => quote! { [T::one(), T::zero(), T::zero()] },
-        "west" => quote! { [T::one(), T::zero(), T::zero()] },
+        "west" => quote! { [-T::one(), T::zero(), T::zero()] },
         "up" => quote! { [T::zero(), T::zero(), T::one()] },
         "down" => quote! { [T::zero(), T::zero(), T::one()] },
         _ => unreachable!(),
     }
 }
 
+fn ned_axis_t(axis: &str) -> impl ToTokens {
+    match axis {
+        "north" => quote! { [T::one(), T::zero(), T::zero()] },
+        "south" => quote! { [-T::one(), T::zero(), T::zero()] },
+        "east" => quote! { [T::zero(), T::one(), T::zero()] },
+        "west" => quote! { [T::zero(), -T::one(), T::zero()] },
+        "down" => quote! { [T::zero(), T::zero(), T::one()] },
+        "up" => quote! { [T::zero(), T::zero(), -T::one()] },
+        _ => unreachable!(),
+    }
+}
+
 fn capitalize(axis: &str) -> &str {
     match axis {
         "north" => "North",
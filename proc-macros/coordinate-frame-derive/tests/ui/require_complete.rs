@@ -0,0 +1,12 @@
+use coordinate_frame_derive::CoordinateFrame;
+
+#[derive(CoordinateFrame)]
+#[coordinate_frame(require_complete)]
+#[repr(u8)]
+pub enum Incomplete {
+    NorthEastDown = 0,
+    EastNorthUp = 9,
+    Other = 48,
+}
+
+fn main() {}